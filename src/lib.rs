@@ -183,9 +183,9 @@ mod tests {
                     for (col_index, (expected_name, expected_type, has_primary_key)) in expected_columns.iter().enumerate() {
                         let column = &seed.stems[col_index];
 
-                        println!("  └─ {}: {} {}",
+                        println!("  └─ {}: {:?} {}",
                                 column.vein,
-                                format!("{:?}", column.fauna),
+                                column.fauna,
                                 if *has_primary_key { "(PRIMARY KEY)" } else { "" });
 
                         // Validar nome da coluna
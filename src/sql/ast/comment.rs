@@ -9,8 +9,16 @@ pub enum Comment {
 }
 
 impl Comment {
-    pub(crate) fn from_pair(_p0: Pair<Rule>) -> Self {
-        // Implementação temporária - apenas retorna um comentário vazio
-        Comment::Line("temp comment".to_string())
+    pub(crate) fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::comment);
+
+        // Guarda o texto sem os delimitadores
+        let inner_pair = pair.into_inner().next().unwrap();
+        let text = inner_pair.as_str();
+        match inner_pair.as_rule() {
+            Rule::line_comment => Comment::Line(text[2..].to_string()),
+            Rule::block_comment => Comment::Block(text[2..text.len() - 2].to_string()),
+            _ => panic!("Unexpected rule in comment: {:?}", inner_pair.as_rule()),
+        }
     }
 }
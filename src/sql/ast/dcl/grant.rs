@@ -0,0 +1,133 @@
+use pest::iterators::Pair;
+use serde::{Deserialize, Serialize};
+use crate::sql::ast::dcl::privilege::{Privileges, Securable};
+use crate::sql::parser::parser::Rule;
+
+// GRANT privilégios ON alvo TO papéis [WITH GRANT OPTION]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Grant {
+    pub privileges: Privileges,
+    pub on: Securable,
+    pub grantees: Vec<String>,
+    pub grant_option: bool,
+}
+
+// REVOKE [GRANT OPTION FOR] privilégios ON alvo FROM papéis [CASCADE | RESTRICT]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Revoke {
+    pub grant_option: bool,
+    pub privileges: Privileges,
+    pub on: Securable,
+    pub grantees: Vec<String>,
+    pub behavior: Option<DropBehavior>,
+}
+
+// GRANT papéis TO papéis
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GrantRole {
+    pub roles: Vec<String>,
+    pub grantees: Vec<String>,
+}
+
+// REVOKE papéis FROM papéis [CASCADE | RESTRICT]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RevokeRole {
+    pub roles: Vec<String>,
+    pub grantees: Vec<String>,
+    pub behavior: Option<DropBehavior>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DropBehavior {
+    Cascade,
+    Restrict,
+}
+
+impl Grant {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::grant);
+
+        let mut inner = pair.into_inner();
+        let privileges = Privileges::from_pair(inner.next().expect("GRANT deve ter privilégios"));
+        let on = Securable::from_pair(inner.next().expect("GRANT deve ter alvo"));
+
+        let mut grantees = Vec::new();
+        let mut grant_option = false;
+
+        for inner_pair in inner {
+            match inner_pair.as_rule() {
+                Rule::ident => grantees.push(inner_pair.as_str().to_string()),
+                Rule::with_grant_option => grant_option = true,
+                _ => panic!("Unexpected rule in GRANT: {:?}", inner_pair.as_rule()),
+            }
+        }
+
+        Self { privileges, on, grantees, grant_option }
+    }
+}
+
+impl Revoke {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::revoke);
+
+        let mut inner = pair.into_inner().peekable();
+        let grant_option = inner.next_if(|p| p.as_rule() == Rule::grant_option_for).is_some();
+        let privileges = Privileges::from_pair(inner.next().expect("REVOKE deve ter privilégios"));
+        let on = Securable::from_pair(inner.next().expect("REVOKE deve ter alvo"));
+
+        let mut grantees = Vec::new();
+        let mut behavior = None;
+
+        for inner_pair in inner {
+            match inner_pair.as_rule() {
+                Rule::ident => grantees.push(inner_pair.as_str().to_string()),
+                Rule::drop_behavior => behavior = Some(DropBehavior::from_pair(inner_pair)),
+                _ => panic!("Unexpected rule in REVOKE: {:?}", inner_pair.as_rule()),
+            }
+        }
+
+        Self { grant_option, privileges, on, grantees, behavior }
+    }
+}
+
+impl GrantRole {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::grant_role);
+
+        let mut inner = pair.into_inner();
+        let roles = role_list(inner.next().expect("GRANT deve ter papéis"));
+        let grantees = role_list(inner.next().expect("GRANT deve ter destinatários"));
+
+        Self { roles, grantees }
+    }
+}
+
+impl RevokeRole {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::revoke_role);
+
+        let mut inner = pair.into_inner();
+        let roles = role_list(inner.next().expect("REVOKE deve ter papéis"));
+        let grantees = role_list(inner.next().expect("REVOKE deve ter destinatários"));
+        let behavior = inner.next().map(DropBehavior::from_pair);
+
+        Self { roles, grantees, behavior }
+    }
+}
+
+fn role_list(pair: Pair<Rule>) -> Vec<String> {
+    assert_eq!(pair.as_rule(), Rule::role_list);
+    pair.into_inner().map(|p| p.as_str().to_string()).collect()
+}
+
+impl DropBehavior {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::drop_behavior);
+
+        match pair.as_str().to_uppercase().as_str() {
+            "CASCADE" => DropBehavior::Cascade,
+            "RESTRICT" => DropBehavior::Restrict,
+            _ => panic!("Unknown drop behavior: {}", pair.as_str()),
+        }
+    }
+}
@@ -0,0 +1,206 @@
+pub mod grant;
+pub mod privilege;
+pub mod role;
+
+use pest::iterators::Pair;
+use serde::{Deserialize, Serialize};
+use crate::sql::ast::dcl::grant::{Grant, GrantRole, Revoke, RevokeRole};
+use crate::sql::ast::dcl::role::{Role, RoleKeyword};
+use crate::sql::parser::parser::{Rule, SQLParser};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DCL {
+    Grant(Grant),
+    Revoke(Revoke),
+    GrantRole(GrantRole),
+    RevokeRole(RevokeRole),
+    CreateRole(Role),
+    DropRole {
+        keyword: RoleKeyword,
+        if_exists: bool,
+        roles: Vec<String>,
+    },
+}
+
+impl DCL {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::DCL);
+
+        let inner_pair = pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::grant => DCL::Grant(Grant::from_pair(inner_pair)),
+            Rule::revoke => DCL::Revoke(Revoke::from_pair(inner_pair)),
+            Rule::grant_role => DCL::GrantRole(GrantRole::from_pair(inner_pair)),
+            Rule::revoke_role => DCL::RevokeRole(RevokeRole::from_pair(inner_pair)),
+            Rule::create_role => DCL::CreateRole(Role::from_pair(inner_pair)),
+            Rule::drop_role => {
+                let mut inner = inner_pair.into_inner().peekable();
+                let keyword = RoleKeyword::from_pair(inner.next().expect("DROP ROLE deve ter ROLE ou USER"));
+                let if_exists = inner.next_if(|p| p.as_rule() == Rule::if_exists).is_some();
+                let roles = inner.map(|p| p.as_str().to_string()).collect();
+
+                DCL::DropRole { keyword, if_exists, roles }
+            }
+            _ => panic!("Unexpected rule: {:?}", inner_pair.as_rule()),
+        }
+    }
+
+    pub fn from_input(input: String) -> Self {
+        let pair = SQLParser::parse_whole(Rule::DCL, &input);
+        DCL::from_pair(pair)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sql::ast::dcl::grant::{DropBehavior, Grant, GrantRole, Revoke, RevokeRole};
+    use crate::sql::ast::dcl::privilege::{Privilege, PrivilegeKind, Privileges, Securable, SecurableKind};
+    use crate::sql::ast::dcl::role::{Role, RoleKeyword, RoleOption};
+    use crate::sql::ast::dcl::DCL;
+    use crate::sql::ast::sql::Sql;
+    use crate::sql::parser::tree::Tree;
+
+    fn privilege(kind: PrivilegeKind) -> Privilege {
+        Privilege { kind, piths: vec![] }
+    }
+
+    #[test]
+    fn grant_with_grant_option() {
+        assert_eq!(
+            DCL::from_input("GRANT SELECT, INSERT ON TABLE t TO analyst, auditor WITH GRANT OPTION".to_string()),
+            DCL::Grant(Grant {
+                privileges: Privileges::Listed(vec![privilege(PrivilegeKind::Select), privilege(PrivilegeKind::Insert)]),
+                on: Securable { kind: SecurableKind::Table, buds: vec!["t".to_string()] },
+                grantees: vec!["analyst".to_string(), "auditor".to_string()],
+                grant_option: true,
+            })
+        );
+    }
+
+    #[test]
+    fn column_privileges_and_implicit_table() {
+        match DCL::from_input("grant update (price, title) on Product to editor".to_string()) {
+            DCL::Grant(grant) => {
+                assert_eq!(grant.privileges, Privileges::Listed(vec![Privilege {
+                    kind: PrivilegeKind::Update,
+                    piths: vec!["price".to_string(), "title".to_string()],
+                }]));
+                assert_eq!(grant.on.kind, SecurableKind::Table);
+                assert!(!grant.grant_option);
+            }
+            other => panic!("Expected GRANT, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn revoke_all_on_schema() {
+        assert_eq!(
+            DCL::from_input("REVOKE ALL ON SCHEMA s FROM intern".to_string()),
+            DCL::Revoke(Revoke {
+                grant_option: false,
+                privileges: Privileges::All,
+                on: Securable { kind: SecurableKind::Schema, buds: vec!["s".to_string()] },
+                grantees: vec!["intern".to_string()],
+                behavior: None,
+            })
+        );
+
+        match DCL::from_input("REVOKE GRANT OPTION FOR ALL PRIVILEGES ON t FROM analyst CASCADE".to_string()) {
+            DCL::Revoke(revoke) => {
+                assert!(revoke.grant_option);
+                assert_eq!(revoke.privileges, Privileges::All);
+                assert_eq!(revoke.behavior, Some(DropBehavior::Cascade));
+            }
+            other => panic!("Expected REVOKE, got {:?}", other),
+        }
+
+        match DCL::from_input("REVOKE SELECT ON t FROM analyst restrict".to_string()) {
+            DCL::Revoke(revoke) => assert_eq!(revoke.behavior, Some(DropBehavior::Restrict)),
+            other => panic!("Expected REVOKE, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn role_membership() {
+        assert_eq!(
+            DCL::from_input("GRANT admin, auditor TO ana, bia".to_string()),
+            DCL::GrantRole(GrantRole {
+                roles: vec!["admin".to_string(), "auditor".to_string()],
+                grantees: vec!["ana".to_string(), "bia".to_string()],
+            })
+        );
+        assert_eq!(
+            DCL::from_input("revoke admin from ana cascade".to_string()),
+            DCL::RevokeRole(RevokeRole {
+                roles: vec!["admin".to_string()],
+                grantees: vec!["ana".to_string()],
+                behavior: Some(DropBehavior::Cascade),
+            })
+        );
+
+        // Com ON continua sendo privilégio
+        assert!(matches!(DCL::from_input("GRANT SELECT ON t TO ana".to_string()), DCL::Grant(_)));
+    }
+
+    #[test]
+    fn create_and_drop_role() {
+        assert_eq!(
+            DCL::from_input("CREATE ROLE reporter WITH LOGIN NOSUPERUSER PASSWORD 'secret'".to_string()),
+            DCL::CreateRole(Role {
+                keyword: RoleKeyword::Role,
+                bud: "reporter".to_string(),
+                options: vec![
+                    RoleOption::Login(true),
+                    RoleOption::Superuser(false),
+                    RoleOption::Password(Some("secret".to_string())),
+                ],
+            })
+        );
+
+        assert_eq!(
+            DCL::from_input("DROP ROLE IF EXISTS reporter, intern".to_string()),
+            DCL::DropRole {
+                keyword: RoleKeyword::Role,
+                if_exists: true,
+                roles: vec!["reporter".to_string(), "intern".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn connection_limit_and_null_password() {
+        match DCL::from_input("CREATE ROLE app WITH CONNECTION LIMIT -1 PASSWORD NULL".to_string()) {
+            DCL::CreateRole(role) => {
+                assert_eq!(role.options, vec![RoleOption::ConnectionLimit(-1), RoleOption::Password(None)]);
+            }
+            other => panic!("Expected CREATE ROLE, got {:?}", other),
+        }
+
+        let input = "CREATE USER app WITH CONNECTION LIMIT 10 PASSWORD NULL";
+        assert_eq!(DCL::from_input(input.to_string()).to_string(), input);
+    }
+
+    #[test]
+    fn user_keeps_its_keyword() {
+        // CREATE USER implica LOGIN; trocar por CREATE ROLE mudaria o significado
+        for input in ["CREATE USER app WITH PASSWORD 'x'", "DROP USER IF EXISTS app"] {
+            assert_eq!(DCL::from_input(input.to_string()).to_string(), input);
+        }
+
+        match DCL::from_input("create user app".to_string()) {
+            DCL::CreateRole(role) => {
+                assert_eq!(role.keyword, RoleKeyword::User);
+                assert!(role.options.is_empty());
+            }
+            other => panic!("Expected CREATE USER, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn permission_script() {
+        let tree = Tree::parse("CREATE ROLE app; GRANT USAGE ON SCHEMA billing TO app; DROP ROLE app;".to_string());
+
+        assert_eq!(tree.sqls.len(), 3);
+        assert!(tree.sqls.iter().all(|sql| matches!(sql, Sql::DCL(_))));
+    }
+}
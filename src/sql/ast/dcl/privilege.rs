@@ -0,0 +1,125 @@
+use pest::iterators::Pair;
+use serde::{Deserialize, Serialize};
+use crate::sql::parser::parser::Rule;
+
+// ALL [PRIVILEGES] ou lista explícita
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Privileges {
+    All,
+    Listed(Vec<Privilege>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Privilege {
+    pub kind: PrivilegeKind,
+    pub piths: Vec<String>, // colunas: SELECT (a, b)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PrivilegeKind {
+    Select,
+    Insert,
+    Update,
+    Delete,
+    Truncate,
+    References,
+    Trigger,
+    Usage,
+    Create,
+    Connect,
+    Temporary, // TEMPORARY / TEMP
+    Execute,
+}
+
+// Alvo do GRANT / REVOKE
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Securable {
+    pub kind: SecurableKind,
+    pub buds: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SecurableKind {
+    Table,
+    Schema,
+    Database,
+    Sequence,
+    Function,
+}
+
+impl Privileges {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::privileges);
+
+        let mut inner = pair.into_inner().peekable();
+        if inner.next_if(|p| p.as_rule() == Rule::all_privileges).is_some() {
+            return Privileges::All;
+        }
+
+        Privileges::Listed(inner.map(Privilege::from_pair).collect())
+    }
+}
+
+impl Privilege {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::privilege);
+
+        let mut inner = pair.into_inner();
+        let kind = PrivilegeKind::from_pair(inner.next().expect("Privilégio deve ter tipo"));
+        let piths = inner.map(|p| p.as_str().to_string()).collect();
+
+        Self { kind, piths }
+    }
+}
+
+impl PrivilegeKind {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::privilege_kind);
+
+        match pair.as_str().to_uppercase().as_str() {
+            "SELECT" => PrivilegeKind::Select,
+            "INSERT" => PrivilegeKind::Insert,
+            "UPDATE" => PrivilegeKind::Update,
+            "DELETE" => PrivilegeKind::Delete,
+            "TRUNCATE" => PrivilegeKind::Truncate,
+            "REFERENCES" => PrivilegeKind::References,
+            "TRIGGER" => PrivilegeKind::Trigger,
+            "USAGE" => PrivilegeKind::Usage,
+            "CREATE" => PrivilegeKind::Create,
+            "CONNECT" => PrivilegeKind::Connect,
+            "TEMPORARY" | "TEMP" => PrivilegeKind::Temporary,
+            "EXECUTE" => PrivilegeKind::Execute,
+            _ => panic!("Unknown privilege: {}", pair.as_str()),
+        }
+    }
+}
+
+impl Securable {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::securable);
+
+        let mut inner = pair.into_inner().peekable();
+        let kind = inner
+            .next_if(|p| p.as_rule() == Rule::securable_kind)
+            .map(SecurableKind::from_pair)
+            .unwrap_or(SecurableKind::Table);
+        let buds = inner.map(|p| p.as_str().to_string()).collect();
+
+        Self { kind, buds }
+    }
+}
+
+impl SecurableKind {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::securable_kind);
+
+        match pair.as_str().to_uppercase().as_str() {
+            "TABLE" => SecurableKind::Table,
+            "SCHEMA" => SecurableKind::Schema,
+            "DATABASE" => SecurableKind::Database,
+            "SEQUENCE" => SecurableKind::Sequence,
+            "FUNCTION" => SecurableKind::Function,
+            _ => panic!("Unknown securable: {}", pair.as_str()),
+        }
+    }
+}
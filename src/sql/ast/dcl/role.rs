@@ -0,0 +1,89 @@
+use pest::iterators::Pair;
+use serde::{Deserialize, Serialize};
+use crate::sql::parser::parser::Rule;
+
+// CREATE ROLE | USER nome [WITH] opções
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Role {
+    pub keyword: RoleKeyword,
+    pub bud: String,
+    pub options: Vec<RoleOption>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RoleKeyword {
+    Role,
+    User, // LOGIN implícito
+}
+
+// Atributos booleanos: LOGIN é Login(true), NOLOGIN é Login(false)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RoleOption {
+    Login(bool),
+    Superuser(bool),
+    CreateDb(bool),
+    CreateRole(bool),
+    Inherit(bool),
+    Password(Option<String>), // PASSWORD NULL é None
+    ConnectionLimit(i64),
+}
+
+impl Role {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::create_role);
+
+        let mut inner = pair.into_inner();
+        let keyword = RoleKeyword::from_pair(inner.next().expect("CREATE ROLE deve ter ROLE ou USER"));
+        let bud = inner.next().expect("CREATE ROLE deve ter nome").as_str().to_string();
+        let options = inner.map(RoleOption::from_pair).collect();
+
+        Self { keyword, bud, options }
+    }
+}
+
+impl RoleKeyword {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::role_keyword);
+
+        match pair.as_str().to_uppercase().as_str() {
+            "ROLE" => RoleKeyword::Role,
+            "USER" => RoleKeyword::User,
+            _ => panic!("Unknown role keyword: {}", pair.as_str()),
+        }
+    }
+}
+
+impl RoleOption {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::role_option);
+
+        if let Some(value) = pair.clone().into_inner().next() {
+            return match value.as_rule() {
+                Rule::string => {
+                    let quoted = value.as_str();
+                    RoleOption::Password(Some(quoted[1..quoted.len() - 1].to_string()))
+                }
+                Rule::null => RoleOption::Password(None),
+                Rule::connection_limit => {
+                    RoleOption::ConnectionLimit(value.as_str().parse().expect("Limite de conexões deve ser inteiro"))
+                }
+                _ => panic!("Unexpected rule in role option: {:?}", value.as_rule()),
+            };
+        }
+
+        let word = pair.as_str().to_uppercase();
+        let (enabled, name) = match word.strip_prefix("NO") {
+            Some(name) => (false, name),
+            None => (true, word.as_str()),
+        };
+
+        match name {
+            "LOGIN" => RoleOption::Login(enabled),
+            "SUPERUSER" => RoleOption::Superuser(enabled),
+            "CREATEDB" => RoleOption::CreateDb(enabled),
+            "CREATEROLE" => RoleOption::CreateRole(enabled),
+            "INHERIT" => RoleOption::Inherit(enabled),
+            _ => panic!("Unknown role option: {}", pair.as_str()),
+        }
+    }
+}
@@ -1,5 +1,4 @@
 use pest::iterators::Pair;
-use pest::Parser;
 use serde::{Deserialize, Serialize};
 use crate::sql::parser::parser::{Rule, SQLParser};
 
@@ -14,7 +13,10 @@ impl Anchor {
     pub fn from_pair(pair: Pair<Rule>) -> Self {
         assert_eq!(pair.as_rule(), Rule::anchor);
 
-        match pair.as_str() {
+        // Normaliza caixa e espaços entre as palavras
+        let words = pair.as_str().split_whitespace().collect::<Vec<_>>().join(" ").to_uppercase();
+
+        match words.as_str() {
             "NOT NULL" => Anchor::Essence,
             "PRIMARY KEY" => Anchor::Nucleus,
             "UNIQUE" => Anchor::Axis,
@@ -23,10 +25,7 @@ impl Anchor {
     }
 
     pub fn from_input(input: String) -> Self {
-        let pair = SQLParser::parse(Rule::anchor, &input)
-            .expect("Failed to parse input")
-            .next()
-            .expect("No pair found");
+        let pair = SQLParser::parse_whole(Rule::anchor, &input);
         Anchor::from_pair(pair)
     }
 }
@@ -1,4 +1,4 @@
-use pest::{iterators::Pair, Parser};
+use pest::iterators::Pair;
 use serde::{Deserialize, Serialize};
 use crate::sql::parser::parser::{Rule, SQLParser};
 
@@ -14,7 +14,7 @@ impl Fauna {
     pub fn from_pair(pair: Pair<Rule>) -> Self {
         assert_eq!(pair.as_rule(), Rule::fauna);
 
-        match pair.as_str() {
+        match pair.as_str().to_uppercase().as_str() {
             "INT" => Fauna::Int,
             "TEXT" => Fauna::Text,
             "BOOLEAN" => Fauna::Bool,
@@ -24,10 +24,7 @@ impl Fauna {
 
 
     pub fn from_input(input: String) -> Self {
-        let pair = SQLParser::parse(Rule::fauna, &input)
-            .expect("Failed to parse input")
-            .next()
-            .expect("No pair found");
+        let pair = SQLParser::parse_whole(Rule::fauna, &input);
         Fauna::from_pair(pair)
     }
 }
@@ -41,5 +38,6 @@ mod tests {
         assert_eq!(Fauna::from_input("INT".to_string()), Fauna::Int);
         assert_eq!(Fauna::from_input("TEXT".to_string()), Fauna::Text);
         assert_eq!(Fauna::from_input("BOOLEAN".to_string()), Fauna::Bool);
+        assert_eq!(Fauna::from_input("int".to_string()), Fauna::Int);
     }
 }
@@ -1,5 +1,4 @@
 use pest::iterators::Pair;
-use pest::Parser;
 use serde::{Deserialize, Serialize};
 use crate::sql::ast::ddl::stem::Stem;
 use crate::sql::parser::parser::{Rule, SQLParser};
@@ -36,10 +35,7 @@ impl Seed {
     }
 
     pub fn from_input(input: String) -> Self {
-        let pair = SQLParser::parse(Rule::seed, &input)
-            .expect("Failed to parse input")
-            .next()
-            .expect("No pair found");
+        let pair = SQLParser::parse_whole(Rule::seed, &input);
         Seed::from_pair(pair)
     }
 }
@@ -0,0 +1,113 @@
+use pest::iterators::Pair;
+use serde::{Deserialize, Serialize};
+use crate::sql::ast::dml::splice::Splice;
+use crate::sql::ast::dql::sift::Sift;
+use crate::sql::parser::parser::Rule;
+
+// Cláusula de upsert de um INSERT
+// ON CONFLICT é o caso comum; não vale a pena colocá-lo em Box
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Conflict {
+    // ON CONFLICT [alvo] DO ... (Postgres/SQLite)
+    OnConflict {
+        target: Option<ConflictTarget>,
+        action: ConflictAction,
+    },
+    // ON DUPLICATE KEY UPDATE ... (MySQL)
+    OnDuplicateKey(Vec<Splice>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConflictTarget {
+    // (col, ...) [WHERE ...], o predicado escolhe um índice parcial
+    Piths {
+        piths: Vec<String>,
+        gate: Option<Sift>,
+    },
+    Constraint(String), // ON CONSTRAINT nome
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConflictAction {
+    Nothing,
+    Update {
+        splices: Vec<Splice>,
+        gate: Option<Sift>,
+    },
+}
+
+impl Conflict {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::conflict);
+
+        let inner_pair = pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::on_conflict => {
+                let mut target = None;
+                let mut action = None;
+
+                for p in inner_pair.into_inner() {
+                    match p.as_rule() {
+                        Rule::conflict_target => target = Some(ConflictTarget::from_pair(p)),
+                        Rule::do_nothing => action = Some(ConflictAction::Nothing),
+                        Rule::do_update => action = Some(ConflictAction::from_pair(p)),
+                        _ => panic!("Unexpected rule in ON CONFLICT: {:?}", p.as_rule()),
+                    }
+                }
+
+                Conflict::OnConflict {
+                    target,
+                    action: action.expect("ON CONFLICT deve ter uma ação"),
+                }
+            }
+            Rule::on_duplicate_key => {
+                Conflict::OnDuplicateKey(inner_pair.into_inner().map(Splice::from_pair).collect())
+            }
+            _ => panic!("Unexpected rule in conflict: {:?}", inner_pair.as_rule()),
+        }
+    }
+}
+
+impl ConflictTarget {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::conflict_target);
+
+        // ON CONSTRAINT começa pela palavra-chave, a lista de colunas por "("
+        if !pair.as_str().starts_with('(') {
+            return ConflictTarget::Constraint(pair.into_inner().next().unwrap().as_str().to_string());
+        }
+
+        let mut piths = Vec::new();
+        let mut gate = None;
+
+        for p in pair.into_inner() {
+            match p.as_rule() {
+                Rule::ident => piths.push(p.as_str().to_string()),
+                Rule::gate => gate = Some(Sift::from_pair(p)),
+                _ => panic!("Unexpected rule in conflict target: {:?}", p.as_rule()),
+            }
+        }
+
+        ConflictTarget::Piths { piths, gate }
+    }
+}
+
+impl ConflictAction {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::do_update);
+
+        let mut splices = Vec::new();
+        let mut gate = None;
+
+        for p in pair.into_inner() {
+            match p.as_rule() {
+                Rule::splice => splices.push(Splice::from_pair(p)),
+                Rule::gate => gate = Some(Sift::from_pair(p)),
+                _ => panic!("Unexpected rule in DO UPDATE: {:?}", p.as_rule()),
+            }
+        }
+
+        ConflictAction::Update { splices, gate }
+    }
+}
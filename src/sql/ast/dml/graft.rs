@@ -0,0 +1,154 @@
+use pest::iterators::Pair;
+use serde::{Deserialize, Serialize};
+use crate::sql::ast::dml::scion::Scion;
+use crate::sql::ast::dql::root::Root;
+use crate::sql::ast::dql::sift::Sift;
+use crate::sql::ast::dql::grove::Grove;
+use crate::sql::parser::parser::{Rule, SQLParser};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Graft {
+    pub grove: Option<Grove>,
+    pub bud: String,
+    pub alias: Option<String>,
+    pub stock: Root,
+    pub on: Sift,
+    pub scions: Vec<Scion>,
+}
+
+impl Graft {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::graft);
+
+        let mut inner = pair.into_inner().peekable();
+
+        // WITH opcional antes do comando
+        let grove = inner.next_if(|p| p.as_rule() == Rule::grove).map(Grove::from_pair);
+
+        // Em seguida, a tabela alvo
+        let bud = inner
+            .next()
+            .expect("MERGE deve ter tabela alvo")
+            .as_str()
+            .to_string();
+
+        let mut alias = None;
+        let mut stock = None;
+        let mut on = None;
+        let mut scions = Vec::new();
+
+        for inner_pair in inner {
+            match inner_pair.as_rule() {
+                Rule::alias => {
+                    alias = Some(inner_pair.into_inner().next().unwrap().as_str().to_string());
+                }
+                Rule::rootlet => {
+                    stock = Some(Root::from_pair(inner_pair));
+                }
+                Rule::sift => {
+                    on = Some(Sift::from_pair(inner_pair));
+                }
+                Rule::scion => {
+                    scions.push(Scion::from_pair(inner_pair));
+                }
+                _ => panic!("Unexpected rule in MERGE: {:?}", inner_pair.as_rule()),
+            }
+        }
+
+        Self {
+            grove,
+            bud,
+            alias,
+            stock: stock.expect("MERGE deve ter USING"),
+            on: on.expect("MERGE deve ter ON"),
+            scions,
+        }
+    }
+
+    pub fn from_input(input: String) -> Self {
+        let pair = SQLParser::parse_whole(Rule::graft, &input);
+        Graft::from_pair(pair)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sql::ast::dml::graft::Graft;
+    use crate::sql::ast::dml::nutrients::Nutrients;
+    use crate::sql::ast::dml::scion::ScionAction;
+    use crate::sql::ast::dql::ident::Ident;
+    use crate::sql::ast::dql::root::Root;
+    use crate::sql::ast::dql::sift::Sift;
+    use crate::sql::parser::tree::Tree;
+
+    #[test]
+    fn merge_with_matched_and_not_matched_branches() {
+        let input = "MERGE INTO Stock AS t USING Delivery d ON sku = code \
+            WHEN MATCHED AND qty = 0 THEN DELETE \
+            WHEN MATCHED THEN UPDATE SET qty = 10, restocked = TRUE \
+            WHEN NOT MATCHED THEN INSERT (sku, qty) VALUES ('x', 10)".to_string();
+        let graft = Graft::from_input(input);
+
+        assert_eq!(graft.bud, "Stock");
+        assert_eq!(graft.alias, Some("t".to_string()));
+        assert_eq!(graft.stock, Root::Bud { bud: "Delivery".to_string(), alias: Some("d".to_string()) });
+        assert!(matches!(graft.on, Sift::Comparison { .. }));
+        assert_eq!(graft.scions.len(), 3);
+
+        assert!(graft.scions[0].matched);
+        assert!(graft.scions[0].gate.is_some());
+        assert_eq!(graft.scions[0].action, ScionAction::Delete);
+
+        assert!(graft.scions[1].matched);
+        assert_eq!(graft.scions[1].gate, None);
+        match &graft.scions[1].action {
+            ScionAction::Update(splices) => assert_eq!(splices.len(), 2),
+            other => panic!("Expected UPDATE branch, got {:?}", other),
+        }
+
+        assert!(!graft.scions[2].matched);
+        assert_eq!(graft.scions[2].action, ScionAction::Insert {
+            piths: vec!["sku".to_string(), "qty".to_string()],
+            furrow: vec![Sift::Literal(Nutrients::Str("x".to_string())), Sift::Literal(Nutrients::Int(10))],
+        });
+    }
+
+    #[test]
+    fn insert_values_from_the_source() {
+        let graft = Graft::from_input(
+            "MERGE INTO s USING d ON s.a = d.a WHEN NOT MATCHED THEN INSERT (a, b) VALUES (d.a, d.b + 1)".to_string(),
+        );
+
+        match &graft.scions[0].action {
+            ScionAction::Insert { piths, furrow } => {
+                assert_eq!(piths, &vec!["a".to_string(), "b".to_string()]);
+                assert_eq!(furrow[0], Sift::Ident(Ident::from_input("d.a".to_string())));
+                assert!(matches!(furrow[1], Sift::Binary { .. }));
+            }
+            other => panic!("Expected INSERT branch, got {:?}", other),
+        }
+
+        let tree = Tree::parse(
+            "MERGE INTO s USING d ON s.a = d.a WHEN NOT MATCHED THEN INSERT (a, b) VALUES (d.a, d.b); SELECT 1".to_string(),
+        );
+        assert_eq!(tree.sqls.len(), 2);
+    }
+
+    #[test]
+    fn merge_do_nothing() {
+        let graft = Graft::from_input("MERGE INTO Stock USING Delivery ON sku = code WHEN NOT MATCHED THEN DO NOTHING".to_string());
+
+        assert_eq!(graft.alias, None);
+        assert_eq!(graft.stock, Root::Bud { bud: "Delivery".to_string(), alias: None });
+        assert_eq!(graft.scions[0].action, ScionAction::Nothing);
+    }
+
+    #[test]
+    fn schema_qualified_target_and_source() {
+        let graft = Graft::from_input("MERGE INTO inv.Stock s USING inv.Delivery d ON s.sku = d.code WHEN NOT MATCHED THEN DO NOTHING".to_string());
+
+        assert_eq!(graft.bud, "inv.Stock");
+        assert_eq!(graft.alias, Some("s".to_string()));
+        assert_eq!(graft.stock, Root::Bud { bud: "inv.Delivery".to_string(), alias: Some("d".to_string()) });
+    }
+}
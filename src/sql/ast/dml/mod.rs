@@ -1,14 +1,27 @@
 pub mod sow;
+pub mod conflict;
+pub mod graft;
 pub mod nutrients;
+pub mod prune;
+pub mod scion;
+pub mod soil;
+pub mod splice;
+pub mod tend;
 
 use pest::iterators::Pair;
 use serde::{Deserialize, Serialize};
+use crate::sql::ast::dml::graft::Graft;
+use crate::sql::ast::dml::prune::Prune;
 use crate::sql::ast::dml::sow::Sow;
+use crate::sql::ast::dml::tend::Tend;
 use crate::sql::parser::parser::Rule;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum DML {
     Sow(Sow),
+    Tend(Tend),
+    Prune(Prune),
+    Graft(Graft),
 }
 
 impl DML {
@@ -18,6 +31,9 @@ impl DML {
         let inner_pair = pair.into_inner().next().unwrap();
         match inner_pair.as_rule() {
             Rule::sow => { DML::Sow(Sow::from_pair(inner_pair)) }
+            Rule::tend => { DML::Tend(Tend::from_pair(inner_pair)) }
+            Rule::prune => { DML::Prune(Prune::from_pair(inner_pair)) }
+            Rule::graft => { DML::Graft(Graft::from_pair(inner_pair)) }
             _ => panic!("Unexpected rule: {:?}", inner_pair.as_rule()),
         }
     }
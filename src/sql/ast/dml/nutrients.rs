@@ -7,6 +7,7 @@ pub enum Nutrients {
     Int(i64),
     Str(String),
     Bool(bool),
+    Null,
 }
 
 impl Nutrients {
@@ -34,6 +35,7 @@ impl Nutrients {
                 };
                 Nutrients::Bool(bool_val)
             }
+            Rule::null => Nutrients::Null,
             _ => panic!("Literal tipo inesperado: {:?}", inner_pair.as_rule()),
         }
     }
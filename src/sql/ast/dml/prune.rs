@@ -0,0 +1,123 @@
+use pest::iterators::Pair;
+use serde::{Deserialize, Serialize};
+use crate::sql::ast::dql::root::Root;
+use crate::sql::ast::dql::sift::Sift;
+use crate::sql::ast::dql::twigs::Twigs;
+use crate::sql::ast::dql::grove::Grove;
+use crate::sql::parser::parser::{Rule, SQLParser};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Prune {
+    pub grove: Option<Grove>,
+    pub bud: String,
+    pub alias: Option<String>,
+    pub roots: Vec<Root>,
+    pub gate: Option<Sift>,
+    pub harvest: Option<Twigs>,
+}
+
+impl Prune {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::prune);
+
+        let mut inner = pair.into_inner().peekable();
+
+        // WITH opcional antes do comando
+        let grove = inner.next_if(|p| p.as_rule() == Rule::grove).map(Grove::from_pair);
+
+        // Em seguida, o nome da tabela
+        let bud = inner
+            .next()
+            .expect("DELETE deve ter nome da tabela")
+            .as_str()
+            .to_string();
+
+        let mut alias = None;
+        let mut roots = Vec::new();
+        let mut gate = None;
+        let mut harvest = None;
+
+        for inner_pair in inner {
+            match inner_pair.as_rule() {
+                Rule::alias => {
+                    alias = Some(inner_pair.into_inner().next().unwrap().as_str().to_string());
+                }
+                // Demais tabelas vêm do USING
+                Rule::root => {
+                    roots.push(Root::from_pair(inner_pair));
+                }
+                Rule::gate => {
+                    gate = Some(Sift::from_pair(inner_pair));
+                }
+                Rule::harvest => {
+                    harvest = Some(Twigs::from_pair(inner_pair.into_inner().next().unwrap()));
+                }
+                _ => panic!("Unexpected rule in DELETE: {:?}", inner_pair.as_rule()),
+            }
+        }
+
+        Self { grove, bud, alias, roots, gate, harvest }
+    }
+
+    pub fn from_input(input: String) -> Self {
+        let pair = SQLParser::parse_whole(Rule::prune, &input);
+        Prune::from_pair(pair)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sql::ast::dml::prune::Prune;
+    use crate::sql::ast::dql::ident::Ident;
+    use crate::sql::ast::dql::root::Root;
+    use crate::sql::ast::dql::sift::Sift;
+    use crate::sql::ast::dql::twigs::Twigs;
+
+    #[test]
+    fn delete_without_where_has_no_gate() {
+        let prune = Prune::from_input("DELETE FROM Session".to_string());
+
+        assert_eq!(prune.bud, "Session");
+        assert_eq!(prune.alias, None);
+        assert!(prune.roots.is_empty());
+        assert_eq!(prune.gate, None);
+    }
+
+    #[test]
+    fn delete_with_using_and_where() {
+        let input = "DELETE FROM Session s USING Account, Device WHERE expired = TRUE".to_string();
+        let prune = Prune::from_input(input);
+
+        assert_eq!(prune.bud, "Session");
+        assert_eq!(prune.alias, Some("s".to_string()));
+        assert_eq!(prune.roots, vec![
+            Root::Bud { bud: "Account".to_string(), alias: None },
+            Root::Bud { bud: "Device".to_string(), alias: None },
+        ]);
+        assert!(prune.gate.is_some());
+    }
+
+    #[test]
+    fn retention_job_deletes_rows_without_owner() {
+        let prune = Prune::from_input("DELETE FROM Session WHERE owner IS NULL OR expired_at is not null".to_string());
+
+        assert_eq!(prune.gate, Some(Sift::Or(
+            Box::new(Sift::IsNull { sift: Box::new(Sift::Ident(Ident::new("owner"))), negated: false }),
+            Box::new(Sift::IsNull { sift: Box::new(Sift::Ident(Ident::new("expired_at"))), negated: true }),
+        )));
+    }
+
+    #[test]
+    fn delete_returning_all() {
+        let prune = Prune::from_input("DELETE FROM Session WHERE expired = TRUE RETURNING *".to_string());
+        assert!(prune.gate.is_some());
+        assert_eq!(prune.harvest, Some(Twigs::All));
+    }
+
+    #[test]
+    fn schema_qualified_table() {
+        let prune = Prune::from_input("DELETE FROM auth.Session s USING auth.Account WHERE s.expired = TRUE".to_string());
+        assert_eq!(prune.bud, "auth.Session");
+        assert_eq!(prune.roots, vec![Root::Bud { bud: "auth.Account".to_string(), alias: None }]);
+    }
+}
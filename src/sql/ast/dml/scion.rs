@@ -0,0 +1,78 @@
+use pest::iterators::Pair;
+use serde::{Deserialize, Serialize};
+use crate::sql::ast::dml::soil::Soil;
+use crate::sql::ast::dml::splice::Splice;
+use crate::sql::ast::dql::sift::Sift;
+use crate::sql::parser::parser::Rule;
+
+// Ramo WHEN [NOT] MATCHED de um MERGE
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Scion {
+    pub matched: bool,
+    pub gate: Option<Sift>,
+    pub action: ScionAction,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ScionAction {
+    Update(Vec<Splice>),
+    Delete,
+    Insert {
+        piths: Vec<String>,
+        furrow: Vec<Sift>, // mesma linha de VALUES do INSERT
+    },
+    Nothing,
+}
+
+impl Scion {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::scion);
+
+        let mut matched = true;
+        let mut gate = None;
+        let mut action = None;
+
+        for inner_pair in pair.into_inner() {
+            match inner_pair.as_rule() {
+                Rule::matched => matched = true,
+                Rule::not_matched => matched = false,
+                Rule::sift => gate = Some(Sift::from_pair(inner_pair)),
+                Rule::scion_update => {
+                    action = Some(ScionAction::Update(
+                        inner_pair.into_inner().map(Splice::from_pair).collect(),
+                    ));
+                }
+                Rule::scion_delete => action = Some(ScionAction::Delete),
+                Rule::scion_insert => action = Some(ScionAction::from_scion_insert(inner_pair)),
+                Rule::do_nothing => action = Some(ScionAction::Nothing),
+                _ => panic!("Unexpected rule in WHEN: {:?}", inner_pair.as_rule()),
+            }
+        }
+
+        Self {
+            matched,
+            gate,
+            action: action.expect("WHEN deve ter uma ação"),
+        }
+    }
+}
+
+impl ScionAction {
+    // Só o ramo INSERT tem estrutura própria; os demais são montados em Scion::from_pair
+    pub fn from_scion_insert(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::scion_insert);
+
+        let mut piths = Vec::new();
+        let mut furrow = Vec::new();
+
+        for inner_pair in pair.into_inner() {
+            match inner_pair.as_rule() {
+                Rule::ident => piths.push(inner_pair.as_str().to_string()),
+                Rule::furrow => furrow = Soil::furrow_from_pair(inner_pair),
+                _ => panic!("Unexpected rule in WHEN ... INSERT: {:?}", inner_pair.as_rule()),
+            }
+        }
+
+        ScionAction::Insert { piths, furrow }
+    }
+}
@@ -0,0 +1,32 @@
+use pest::iterators::Pair;
+use serde::{Deserialize, Serialize};
+use crate::sql::ast::dql::sift::Sift;
+use crate::sql::ast::dql::DQL;
+use crate::sql::parser::parser::Rule;
+
+// Origem das linhas de um INSERT
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Soil {
+    Furrows(Vec<Vec<Sift>>), // VALUES (...), (...)
+    Sample(Box<DQL>),        // SELECT ...
+}
+
+impl Soil {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::soil);
+
+        let inner_pair = pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::furrows => Soil::Furrows(inner_pair.into_inner().map(Soil::furrow_from_pair).collect()),
+            Rule::DQL => Soil::Sample(Box::new(DQL::from_pair(inner_pair))),
+            _ => panic!("Unexpected rule in soil: {:?}", inner_pair.as_rule()),
+        }
+    }
+
+    // Uma linha de VALUES; o ramo INSERT do MERGE usa a mesma forma
+    pub fn furrow_from_pair(pair: Pair<Rule>) -> Vec<Sift> {
+        assert_eq!(pair.as_rule(), Rule::furrow);
+
+        pair.into_inner().map(Sift::from_pair).collect()
+    }
+}
@@ -1,22 +1,31 @@
 use pest::iterators::Pair;
 use serde::{Deserialize, Serialize};
-use crate::sql::ast::dml::nutrients::Nutrients;
-use crate::sql::parser::parser::Rule;
+use crate::sql::ast::dml::conflict::Conflict;
+use crate::sql::ast::dml::soil::Soil;
+use crate::sql::ast::dql::twigs::Twigs;
+use crate::sql::ast::dql::grove::Grove;
+use crate::sql::parser::parser::{Rule, SQLParser};
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Sow {
+    pub grove: Option<Grove>,
     pub bud: String,
     pub piths: Vec<String>,
-    pub nutrients: Vec<Nutrients>,
+    pub soil: Soil,
+    pub conflict: Option<Conflict>,
+    pub harvest: Option<Twigs>,
 }
 
 impl Sow {
     pub(crate) fn from_pair(pair: Pair<Rule>) -> Self {
         assert_eq!(pair.as_rule(), Rule::sow);
 
-        let mut inner = pair.into_inner();
+        let mut inner = pair.into_inner().peekable();
 
-        // Primeiro é o nome da tabela
+        // WITH opcional antes do comando
+        let grove = inner.next_if(|p| p.as_rule() == Rule::grove).map(Grove::from_pair);
+
+        // Em seguida, o nome da tabela
         let table = inner
             .next()
             .expect("INSERT deve ter nome da tabela")
@@ -24,24 +33,232 @@ impl Sow {
             .to_string();
 
         let mut columns = Vec::new();
-        let mut values = Vec::new();
+        let mut soil = None;
+        let mut conflict = None;
+        let mut harvest = None;
 
         for inner_pair in inner {
             match inner_pair.as_rule() {
                 Rule::ident => {
                     columns.push(inner_pair.as_str().to_string());
                 }
-                Rule::nutrient => {
-                    values.push(Nutrients::from_pair(inner_pair));
+                Rule::soil => {
+                    soil = Some(Soil::from_pair(inner_pair));
+                }
+                Rule::conflict => {
+                    conflict = Some(Conflict::from_pair(inner_pair));
+                }
+                Rule::harvest => {
+                    harvest = Some(Twigs::from_pair(inner_pair.into_inner().next().unwrap()));
                 }
                 _ => { panic!("sem suporte para {:#?}", inner_pair.as_rule()) }
             }
         }
 
         Self {
+            grove,
             bud: table,
             piths: columns,
-            nutrients: values,
+            soil: soil.expect("INSERT deve ter VALUES ou SELECT"),
+            conflict,
+            harvest,
+        }
+    }
+
+    pub fn from_input(input: String) -> Self {
+        let pair = SQLParser::parse_whole(Rule::sow, &input);
+        Sow::from_pair(pair)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sql::ast::dml::conflict::{Conflict, ConflictAction, ConflictTarget};
+    use crate::sql::ast::dml::nutrients::Nutrients;
+    use crate::sql::ast::dml::soil::Soil;
+    use crate::sql::ast::dml::sow::Sow;
+    use crate::sql::ast::dql::ident::Ident;
+    use crate::sql::ast::dql::root::Root;
+    use crate::sql::ast::dql::sample::Sample;
+    use crate::sql::ast::dql::sift::Sift;
+    use crate::sql::ast::dql::twigs::{Twig, Twigs};
+    use crate::sql::ast::dql::DQL;
+    use crate::sql::parser::tree::Tree;
+
+    #[test]
+    fn values_rows_are_furrows() {
+        let input = "INSERT INTO Product (id, title) VALUES (1, 'Lamp'), (2, 'Desk')".to_string();
+        let sow = Sow::from_input(input);
+
+        assert_eq!(sow.bud, "Product");
+        assert_eq!(sow.piths, vec!["id", "title"]);
+        assert_eq!(sow.soil, Soil::Furrows(vec![
+            vec![Sift::Literal(Nutrients::Int(1)), Sift::Literal(Nutrients::Str("Lamp".to_string()))],
+            vec![Sift::Literal(Nutrients::Int(2)), Sift::Literal(Nutrients::Str("Desk".to_string()))],
+        ]));
+    }
+
+    #[test]
+    fn values_rows_hold_expressions_and_default() {
+        let sow = Sow::from_input("INSERT INTO Event (id, at, note, kind) VALUES (-1, now(), NULL, DEFAULT)".to_string());
+
+        let Soil::Furrows(furrows) = sow.soil else { panic!("Expected VALUES, got {:?}", sow.soil) };
+        assert!(matches!(furrows[0][0], Sift::Unary { .. }));
+        assert!(matches!(furrows[0][1], Sift::Call(_)));
+        assert_eq!(furrows[0][2], Sift::Literal(Nutrients::Null));
+        assert_eq!(furrows[0][3], Sift::Default);
+    }
+
+    #[test]
+    fn select_source_is_sample() {
+        let input = "INSERT INTO archive (a, b) SELECT a, b FROM live WHERE a > 10".to_string();
+        let sow = Sow::from_input(input);
+
+        assert_eq!(sow.bud, "archive");
+        assert_eq!(sow.piths, vec!["a", "b"]);
+        match sow.soil {
+            Soil::Sample(dql) => {
+                let DQL::Sample(sample) = *dql else { panic!("Expected SELECT, got {:?}", dql) };
+                assert_eq!(sample.roots, vec![Root::Bud { bud: "live".to_string(), alias: None }]);
+                assert_eq!(sample.piths, Twigs::from_input("a, b".to_string()));
+                assert!(sample.gate.is_some());
+            }
+            other => panic!("Expected SELECT source, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn set_operation_source_is_bouquet() {
+        let sow = Sow::from_input("INSERT INTO archive (a) SELECT a FROM x UNION SELECT a FROM y ORDER BY a".to_string());
+
+        match sow.soil {
+            Soil::Sample(dql) => {
+                let DQL::Bouquet(bouquet) = *dql else { panic!("Expected UNION, got {:?}", dql) };
+                assert_eq!(bouquet.ranks.len(), 1);
+            }
+            other => panic!("Expected SELECT source, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn on_conflict_do_update_with_excluded() {
+        let input = "INSERT INTO Stock (id, qty) VALUES (1, 5) \
+            ON CONFLICT (id) DO UPDATE SET qty = EXCLUDED.qty WHERE qty < 100".to_string();
+        let sow = Sow::from_input(input);
+
+        match sow.conflict {
+            Some(Conflict::OnConflict { target, action: ConflictAction::Update { splices, gate } }) => {
+                assert_eq!(target, Some(ConflictTarget::Piths { piths: vec!["id".to_string()], gate: None }));
+                assert_eq!(splices.len(), 1);
+                assert_eq!(splices[0].vein, "qty");
+                assert_eq!(splices[0].sift, Sift::Excluded("qty".to_string()));
+                assert!(gate.is_some());
+            }
+            other => panic!("Expected ON CONFLICT DO UPDATE, got {:?}", other),
         }
     }
+
+    #[test]
+    fn on_conflict_with_partial_index_predicate() {
+        let sow = Sow::from_input("INSERT INTO t (a) VALUES (2) ON CONFLICT (a) WHERE a > 1 DO NOTHING".to_string());
+
+        match sow.conflict {
+            Some(Conflict::OnConflict { target: Some(ConflictTarget::Piths { piths, gate: Some(gate) }), action }) => {
+                assert_eq!(piths, vec!["a".to_string()]);
+                assert!(matches!(gate, Sift::Comparison { .. }));
+                assert_eq!(action, ConflictAction::Nothing);
+            }
+            other => panic!("Expected ON CONFLICT with a predicate, got {:?}", other),
+        }
+
+        // Atribuição por linha não é suportada e precisa falhar, não ser lida pela metade
+        assert!(Tree::try_parse("INSERT INTO t (a, b) VALUES (1, 2) ON CONFLICT (a) DO UPDATE SET (a, b) = (1, 2)".to_string()).is_err());
+    }
+
+    #[test]
+    fn on_conflict_do_nothing() {
+        let sow = Sow::from_input("INSERT INTO Stock (id) VALUES (1) ON CONFLICT DO NOTHING".to_string());
+        assert_eq!(sow.conflict, Some(Conflict::OnConflict { target: None, action: ConflictAction::Nothing }));
+
+        let sow = Sow::from_input("INSERT INTO Stock (id) VALUES (1) ON CONFLICT ON CONSTRAINT stock_pk DO NOTHING".to_string());
+        assert_eq!(sow.conflict, Some(Conflict::OnConflict {
+            target: Some(ConflictTarget::Constraint("stock_pk".to_string())),
+            action: ConflictAction::Nothing,
+        }));
+    }
+
+    #[test]
+    fn on_duplicate_key_update() {
+        let input = "INSERT INTO Stock (id, qty) VALUES (1, 5) ON DUPLICATE KEY UPDATE qty = VALUES(qty)".to_string();
+        let sow = Sow::from_input(input);
+
+        match sow.conflict {
+            Some(Conflict::OnDuplicateKey(splices)) => {
+                assert_eq!(splices[0].vein, "qty");
+                assert_eq!(splices[0].sift, Sift::InsertValue("qty".to_string()));
+            }
+            other => panic!("Expected ON DUPLICATE KEY UPDATE, got {:?}", other),
+        }
+
+        // O MySQL não conhece EXCLUDED, então a grafia precisa voltar igual
+        let input = "INSERT INTO Stock (id, qty) VALUES (1, 5) ON DUPLICATE KEY UPDATE qty = qty + VALUES(qty)";
+        assert_eq!(Sow::from_input(input.to_string()).to_string(), input);
+    }
+
+    #[test]
+    fn excluded_is_only_special_inside_an_upsert() {
+        let sample = Sample::from_input("SELECT excluded.a FROM excluded".to_string());
+        assert_eq!(sample.piths, Twigs::Named(vec![Twig::Sift {
+            sift: Sift::Ident(Ident::from_input("excluded.a".to_string())),
+            alias: None,
+        }]));
+
+        assert!(Tree::try_parse("SELECT VALUES(a) FROM t".to_string()).is_err());
+        assert!(Tree::try_parse("UPDATE t SET a = VALUES(a)".to_string()).is_err());
+
+        let sow = Sow::from_input("INSERT INTO t (a) VALUES (1) ON CONFLICT (a) DO UPDATE SET a = excluded.a RETURNING excluded.a".to_string());
+        let Some(Conflict::OnConflict { action: ConflictAction::Update { splices, .. }, .. }) = sow.conflict else {
+            panic!("Expected ON CONFLICT DO UPDATE, got {:?}", sow.conflict)
+        };
+        assert_eq!(splices[0].sift, Sift::Excluded("a".to_string()));
+        assert_eq!(sow.harvest, Some(Twigs::from_input("excluded.a".to_string())));
+    }
+
+    #[test]
+    fn returning_generated_columns() {
+        let sow = Sow::from_input("INSERT INTO Account (name) VALUES ('ana') RETURNING id, created_at".to_string());
+        assert_eq!(sow.harvest, Some(Twigs::from_input("id, created_at".to_string())));
+
+        let sow = Sow::from_input("INSERT INTO Account (name) VALUES ('ana') RETURNING id AS account_id".to_string());
+        assert_eq!(sow.harvest, Some(Twigs::Named(vec![Twig::Sift {
+            sift: Sift::Ident(Ident::new("id")),
+            alias: Some("account_id".to_string()),
+        }])));
+
+        let sow = Sow::from_input("INSERT INTO Account (name) VALUES ('ana') ON CONFLICT DO NOTHING RETURNING *".to_string());
+        assert!(sow.conflict.is_some());
+        assert_eq!(sow.harvest, Some(Twigs::All));
+    }
+
+    #[test]
+    fn returning_expressions_with_aliases() {
+        let sow = Sow::from_input(
+            "INSERT INTO Line (price, qty) VALUES (2, 3) RETURNING id, price * qty AS total, lower(sku) code".to_string(),
+        );
+
+        let twigs = match sow.harvest {
+            Some(Twigs::Named(twigs)) => twigs,
+            other => panic!("Expected named RETURNING, got {:?}", other),
+        };
+        assert_eq!(twigs.len(), 3);
+        assert!(matches!(&twigs[1], Twig::Sift { sift: Sift::Binary { .. }, alias: Some(alias) } if alias == "total"));
+        assert!(matches!(&twigs[2], Twig::Sift { sift: Sift::Call(_), alias: Some(alias) } if alias == "code"));
+    }
+
+    #[test]
+    fn schema_qualified_table() {
+        let sow = Sow::from_input("INSERT INTO sales.Product (id) VALUES (1)".to_string());
+        assert_eq!(sow.bud, "sales.Product");
+        assert_eq!(sow.piths, vec!["id"]);
+    }
 }
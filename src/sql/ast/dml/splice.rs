@@ -0,0 +1,30 @@
+use pest::iterators::Pair;
+use serde::{Deserialize, Serialize};
+use crate::sql::ast::dql::ident::Ident;
+use crate::sql::ast::dql::sift::Sift;
+use crate::sql::parser::parser::Rule;
+
+// Atribuição "coluna = expressão" de um SET
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Splice {
+    pub vein: Ident,
+    pub sift: Sift,
+}
+
+impl Splice {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::splice);
+
+        let mut inner = pair.into_inner();
+
+        let vein = Ident::from_pair(inner
+            .next()
+            .expect("SET deve ter nome da coluna"));
+
+        let sift = Sift::from_pair(inner
+            .next()
+            .expect("SET deve ter uma expressão"));
+
+        Self { vein, sift }
+    }
+}
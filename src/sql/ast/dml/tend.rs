@@ -0,0 +1,159 @@
+use pest::iterators::Pair;
+use serde::{Deserialize, Serialize};
+use crate::sql::ast::dml::splice::Splice;
+use crate::sql::ast::dql::root::Root;
+use crate::sql::ast::dql::sift::Sift;
+use crate::sql::ast::dql::twigs::Twigs;
+use crate::sql::ast::dql::grove::Grove;
+use crate::sql::parser::parser::{Rule, SQLParser};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Tend {
+    pub grove: Option<Grove>,
+    pub bud: String,
+    pub alias: Option<String>,
+    pub splices: Vec<Splice>,
+    pub roots: Vec<Root>,
+    pub gate: Option<Sift>,
+    pub harvest: Option<Twigs>,
+}
+
+impl Tend {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::tend);
+
+        let mut inner = pair.into_inner().peekable();
+
+        // WITH opcional antes do comando
+        let grove = inner.next_if(|p| p.as_rule() == Rule::grove).map(Grove::from_pair);
+
+        // Em seguida, o nome da tabela
+        let bud = inner
+            .next()
+            .expect("UPDATE deve ter nome da tabela")
+            .as_str()
+            .to_string();
+
+        let mut alias = None;
+        let mut splices = Vec::new();
+        let mut roots = Vec::new();
+        let mut gate = None;
+        let mut harvest = None;
+
+        for inner_pair in inner {
+            match inner_pair.as_rule() {
+                Rule::alias => {
+                    alias = Some(inner_pair.into_inner().next().unwrap().as_str().to_string());
+                }
+                Rule::splice => {
+                    splices.push(Splice::from_pair(inner_pair));
+                }
+                Rule::roots => {
+                    roots = Root::from_roots(inner_pair);
+                }
+                Rule::gate => {
+                    gate = Some(Sift::from_pair(inner_pair));
+                }
+                Rule::harvest => {
+                    harvest = Some(Twigs::from_pair(inner_pair.into_inner().next().unwrap()));
+                }
+                _ => panic!("Unexpected rule in UPDATE: {:?}", inner_pair.as_rule()),
+            }
+        }
+
+        Self { grove, bud, alias, splices, roots, gate, harvest }
+    }
+
+    pub fn from_input(input: String) -> Self {
+        let pair = SQLParser::parse_whole(Rule::tend, &input);
+        Tend::from_pair(pair)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sql::ast::dml::nutrients::Nutrients;
+    use crate::sql::ast::dml::tend::Tend;
+    use crate::sql::ast::dql::ident::Ident;
+    use crate::sql::ast::dql::root::Root;
+    use crate::sql::ast::dql::sift::Sift;
+    use crate::sql::ast::dql::twigs::Twigs;
+
+    #[test]
+    fn update_with_where() {
+        let input = "UPDATE Product SET price = 10, available = FALSE WHERE id = 3".to_string();
+        let tend = Tend::from_input(input);
+
+        assert_eq!(tend.bud, "Product");
+        assert_eq!(tend.alias, None);
+        assert_eq!(tend.splices.len(), 2);
+        assert_eq!(tend.splices[0].vein, "price");
+        assert_eq!(tend.splices[0].sift, Sift::Literal(Nutrients::Int(10)));
+        assert_eq!(tend.splices[1].vein, "available");
+        assert_eq!(tend.splices[1].sift, Sift::Literal(Nutrients::Bool(false)));
+        assert!(tend.roots.is_empty());
+        assert!(tend.gate.is_some());
+    }
+
+    #[test]
+    fn null_is_a_literal_not_a_column() {
+        let tend = Tend::from_input("UPDATE t SET a = NULL, b = null WHERE c IS NOT NULL".to_string());
+
+        assert_eq!(tend.splices[0].sift, Sift::Literal(Nutrients::Null));
+        assert_eq!(tend.splices[1].sift, Sift::Literal(Nutrients::Null));
+        assert_eq!(tend.gate, Some(Sift::IsNull { sift: Box::new(Sift::Ident(Ident::new("c"))), negated: true }));
+    }
+
+    #[test]
+    fn update_with_alias_and_from() {
+        let input = "update Product AS p set price = cost from Supply, Vendor".to_string();
+        let tend = Tend::from_input(input);
+
+        assert_eq!(tend.bud, "Product");
+        assert_eq!(tend.alias, Some("p".to_string()));
+        assert_eq!(tend.splices[0].sift, Sift::Ident(Ident::new("cost")));
+        assert_eq!(tend.roots, vec![
+            Root::Bud { bud: "Supply".to_string(), alias: None },
+            Root::Bud { bud: "Vendor".to_string(), alias: None },
+        ]);
+        assert_eq!(tend.gate, None);
+
+        let tend = Tend::from_input("UPDATE Product p SET price = 1".to_string());
+        assert_eq!(tend.alias, Some("p".to_string()));
+    }
+
+    #[test]
+    fn update_returning() {
+        let tend = Tend::from_input("UPDATE Product SET price = 10 WHERE id = 3 RETURNING id, price".to_string());
+        assert!(tend.gate.is_some());
+        assert_eq!(tend.harvest, Some(Twigs::from_input("id, price".to_string())));
+    }
+
+    #[test]
+    fn update_with_cte() {
+        let tend = Tend::from_input(
+            "WITH stale AS (SELECT id FROM Product WHERE available = FALSE) UPDATE Product SET price = 0 FROM stale".to_string(),
+        );
+
+        let grove = tend.grove.expect("UPDATE deveria ter WITH");
+        assert_eq!(grove.saplings[0].bud, "stale");
+        assert_eq!(tend.bud, "Product");
+    }
+
+    #[test]
+    fn qualified_assignment_and_join_condition() {
+        let tend = Tend::from_input("UPDATE Product p SET p.price = s.cost FROM Supply s WHERE s.product_id = p.id".to_string());
+
+        assert_eq!(tend.splices[0].vein, "p.price");
+        assert_eq!(tend.splices[0].sift, Sift::Ident(Ident::from_input("s.cost".to_string())));
+        assert!(tend.splices[0].vein.matches(&Ident::new("price")));
+    }
+
+    #[test]
+    fn schema_qualified_table() {
+        let tend = Tend::from_input("UPDATE sales.Product p SET price = 1 FROM sales.Supply".to_string());
+        assert_eq!(tend.bud, "sales.Product");
+        assert_eq!(tend.alias, Some("p".to_string()));
+        assert_eq!(tend.roots, vec![Root::Bud { bud: "sales.Supply".to_string(), alias: None }]);
+    }
+}
@@ -0,0 +1,68 @@
+use pest::iterators::Pair;
+use serde::{Deserialize, Serialize};
+use crate::sql::ast::dql::rank::Rank;
+use crate::sql::ast::dql::sift::Sift;
+use crate::sql::parser::parser::Rule;
+
+// Chamada de agregação: COUNT(*), COUNT(DISTINCT a, b), SUM(x ORDER BY y) FILTER (WHERE ...)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Aggregate {
+    pub func: AggregateFn,
+    pub distinct: bool,
+    pub args: Vec<Sift>, // Vazio é o "*" de COUNT(*)
+    pub ranks: Vec<Rank>,
+    pub filter: Option<Box<Sift>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AggregateFn {
+    Count,
+    Sum,
+    Avg,
+    Min,
+    Max,
+}
+
+impl Aggregate {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::aggregate);
+
+        let mut inner = pair.into_inner();
+        let func = AggregateFn::from_pair(inner.next().expect("Agregação deve ter função"));
+
+        let mut distinct = false;
+        let mut args = Vec::new();
+        let mut ranks = Vec::new();
+        let mut filter = None;
+
+        for inner_pair in inner {
+            match inner_pair.as_rule() {
+                Rule::distinct => distinct = true,
+                Rule::sift => args.push(Sift::from_pair(inner_pair)),
+                Rule::order_by => ranks = Rank::from_order_by(inner_pair),
+                Rule::filter => {
+                    let sift = Sift::from_pair(inner_pair.into_inner().next().unwrap());
+                    filter = Some(Box::new(sift));
+                }
+                _ => panic!("Unexpected rule in aggregate: {:?}", inner_pair.as_rule()),
+            }
+        }
+
+        Self { func, distinct, args, ranks, filter }
+    }
+}
+
+impl AggregateFn {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::aggregate_fn);
+
+        match pair.as_str().to_uppercase().as_str() {
+            "COUNT" => AggregateFn::Count,
+            "SUM" => AggregateFn::Sum,
+            "AVG" => AggregateFn::Avg,
+            "MIN" => AggregateFn::Min,
+            "MAX" => AggregateFn::Max,
+            _ => panic!("Unknown aggregate function: {}", pair.as_str()),
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use pest::iterators::Pair;
+use serde::{Deserialize, Serialize};
+use crate::sql::ast::dql::crop::Crop;
+use crate::sql::ast::dql::grove::Grove;
+use crate::sql::ast::dql::rank::Rank;
+use crate::sql::ast::dql::sample::Sample;
+use crate::sql::parser::parser::{Rule, SQLParser};
+
+// Consulta composta por operações de conjunto
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Bouquet {
+    pub grove: Option<Grove>,
+    pub blend: Blend,
+    pub ranks: Vec<Rank>,
+    pub crop: Option<Crop>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Blend {
+    Sample(Box<Sample>),
+    Bouquet(Box<Bouquet>), // operando entre parênteses
+    Cross {
+        left: Box<Blend>,
+        cross: Cross,
+        all: bool,
+        right: Box<Blend>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Cross {
+    Union,
+    Intersect,
+    Except,
+}
+
+impl Bouquet {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::bouquet);
+
+        let mut inner = pair.into_inner().peekable();
+
+        // WITH opcional antes do comando
+        let grove = inner.next_if(|p| p.as_rule() == Rule::grove).map(Grove::from_pair);
+        let blend = Blend::from_pair(inner.next().expect("Bouquet deve ter operandos"));
+
+        let mut ranks = Vec::new();
+        let mut crop = None;
+
+        for inner_pair in inner {
+            match inner_pair.as_rule() {
+                Rule::order_by => ranks = Rank::from_order_by(inner_pair),
+                Rule::crop => crop = Some(Crop::from_pair(inner_pair)),
+                _ => panic!("Unexpected rule after set operation: {:?}", inner_pair.as_rule()),
+            }
+        }
+
+        Self { grove, blend, ranks, crop }
+    }
+
+    pub fn from_input(input: String) -> Self {
+        let pair = SQLParser::parse_whole(Rule::bouquet, &input);
+        Bouquet::from_pair(pair)
+    }
+
+    // Parênteses sem operação de conjunto nem nada em volta: (SELECT 1) é só o SELECT
+    pub(crate) fn is_bare(&self) -> bool {
+        self.grove.is_none() && self.ranks.is_empty() && self.crop.is_none()
+            && !matches!(self.blend, Blend::Cross { .. })
+    }
+}
+
+impl Blend {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        match pair.as_rule() {
+            // Operações do mesmo nível associam à esquerda
+            Rule::blend | Rule::blend_term => {
+                let mut inner = pair.into_inner();
+                let mut blend = Blend::from_pair(inner.next().unwrap());
+
+                while let (Some(cross_pair), Some(right_pair)) = (inner.next(), inner.next()) {
+                    let upper = cross_pair.as_str().to_uppercase();
+                    let cross = match upper.split_whitespace().next().unwrap() {
+                        "UNION" => Cross::Union,
+                        "INTERSECT" => Cross::Intersect,
+                        "EXCEPT" => Cross::Except,
+                        _ => panic!("Unknown set operation: {}", cross_pair.as_str()),
+                    };
+
+                    blend = Blend::Cross {
+                        left: Box::new(blend),
+                        cross,
+                        all: upper.ends_with("ALL"),
+                        right: Box::new(Blend::from_pair(right_pair)),
+                    };
+                }
+                blend
+            }
+            Rule::blend_leaf => Blend::from_pair(pair.into_inner().next().unwrap()),
+            Rule::blend_sample | Rule::sample => Blend::Sample(Box::new(Sample::from_pair(pair))),
+            Rule::bouquet => {
+                let bouquet = Bouquet::from_pair(pair);
+                match bouquet.is_bare() {
+                    true => bouquet.blend,
+                    false => Blend::Bouquet(Box::new(bouquet)),
+                }
+            }
+            _ => panic!("Unexpected rule in set operation: {:?}", pair.as_rule()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sql::ast::dql::bouquet::{Blend, Bouquet, Cross};
+    use crate::sql::ast::dql::crop::{Crop, Limit};
+    use crate::sql::ast::dql::sample::Sample;
+    use crate::sql::ast::dql::DQL;
+    use crate::sql::ast::sql::Sql;
+    use crate::sql::parser::tree::Tree;
+
+    fn dql(input: &str) -> DQL {
+        match Tree::parse(input.to_string()).sqls.remove(0) {
+            Sql::DQL(dql) => dql,
+            other => panic!("Expected query, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn intersect_binds_tighter_than_union() {
+        let bouquet = Bouquet::from_input(
+            "SELECT a FROM x UNION ALL SELECT a FROM y INTERSECT SELECT a FROM z ORDER BY a LIMIT 5".to_string(),
+        );
+
+        assert_eq!(bouquet.ranks.len(), 1);
+        assert_eq!(bouquet.crop, Some(Crop { limit: Some(Limit::Count(5)), offset: None, fetch: None }));
+
+        match bouquet.blend {
+            Blend::Cross { left, cross: Cross::Union, all: true, right } => {
+                match *left {
+                    Blend::Sample(sample) => assert!(sample.ranks.is_empty()),
+                    other => panic!("Expected SELECT, got {:?}", other),
+                }
+                assert!(matches!(*right, Blend::Cross { cross: Cross::Intersect, all: false, .. }));
+            }
+            other => panic!("Expected UNION ALL, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn except_is_left_associative_and_parentheses_group() {
+        let bouquet = Bouquet::from_input(
+            "SELECT a FROM x EXCEPT (SELECT a FROM y UNION SELECT a FROM z) EXCEPT (SELECT a FROM w LIMIT 1)".to_string(),
+        );
+
+        match bouquet.blend {
+            Blend::Cross { left, cross: Cross::Except, right, .. } => {
+                match *right {
+                    Blend::Sample(sample) => assert!(sample.crop.is_some()),
+                    other => panic!("Expected parenthesised SELECT, got {:?}", other),
+                }
+                match *left {
+                    Blend::Cross { cross: Cross::Except, right, .. } => {
+                        assert!(matches!(*right, Blend::Bouquet(_)));
+                    }
+                    other => panic!("Expected EXCEPT, got {:?}", other),
+                }
+            }
+            other => panic!("Expected EXCEPT, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parenthesised_query_at_top_level() {
+        assert_eq!(dql("(SELECT 1)"), DQL::Sample(Sample::from_input("SELECT 1".to_string())));
+
+        let DQL::Bouquet(bouquet) = dql("(SELECT a FROM t UNION SELECT b FROM u) ORDER BY 1") else {
+            panic!("Expected set operation")
+        };
+        assert_eq!(bouquet.ranks.len(), 1);
+        assert!(matches!(bouquet.blend, Blend::Bouquet(_)));
+
+        assert_eq!(
+            dql("((SELECT 1) UNION (SELECT 2))"),
+            DQL::Bouquet(Bouquet::from_input("SELECT 1 UNION SELECT 2".to_string()))
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use pest::iterators::Pair;
+use serde::{Deserialize, Serialize};
+use crate::sql::ast::dql::rank::Rank;
+use crate::sql::ast::dql::sift::Sift;
+use crate::sql::parser::parser::Rule;
+
+// Chamada de função genérica: lower(x), public.f(a, b), string_agg(DISTINCT x, ',' ORDER BY x)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Call {
+    pub schema: Option<String>,
+    pub name: String,
+    pub distinct: bool,
+    pub args: Vec<Sift>,
+    pub ranks: Vec<Rank>, // ORDER BY dentro da chamada
+    pub filter: Option<Box<Sift>>,
+}
+
+impl Call {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::call);
+
+        let mut names = Vec::new();
+        let mut distinct = false;
+        let mut args = Vec::new();
+        let mut ranks = Vec::new();
+        let mut filter = None;
+
+        for inner_pair in pair.into_inner() {
+            match inner_pair.as_rule() {
+                Rule::ident | Rule::call_name => names.push(inner_pair.as_str().to_string()),
+                Rule::distinct => distinct = true,
+                Rule::sift => args.push(Sift::from_pair(inner_pair)),
+                Rule::order_by => ranks = Rank::from_order_by(inner_pair),
+                Rule::filter => {
+                    let sift = Sift::from_pair(inner_pair.into_inner().next().unwrap());
+                    filter = Some(Box::new(sift));
+                }
+                _ => panic!("Unexpected rule in call: {:?}", inner_pair.as_rule()),
+            }
+        }
+
+        // O último nome é a função; o anterior, se houver, o schema
+        let name = names.pop().expect("Chamada deve ter nome");
+        let schema = names.pop();
+
+        Self { schema, name, distinct, args, ranks, filter }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sql::ast::ddl::fauna::Fauna;
+    use crate::sql::ast::dml::nutrients::Nutrients;
+    use crate::sql::ast::dql::aggregate::{Aggregate, AggregateFn};
+    use crate::sql::ast::dql::call::Call;
+    use crate::sql::ast::dql::ident::Ident;
+    use crate::sql::ast::dql::op::{Op, UnaryOp};
+    use crate::sql::ast::dql::rank::Rank;
+    use crate::sql::ast::dql::sift::Sift;
+    use crate::sql::ast::dql::window::Over;
+
+    fn ident(name: &str) -> Sift {
+        Sift::Ident(Ident::new(name))
+    }
+
+    fn call(name: &str, args: Vec<Sift>) -> Sift {
+        Sift::Call(Call { schema: None, name: name.to_string(), distinct: false, args, ranks: vec![], filter: None })
+    }
+
+    #[test]
+    fn plain_and_qualified_calls() {
+        assert_eq!(
+            Sift::from_input("lower(name) = 'x'".to_string()),
+            Sift::Comparison {
+                left: Box::new(call("lower", vec![ident("name")])),
+                op: Op::Eq,
+                right: Box::new(Sift::Literal(Nutrients::Str("x".to_string()))),
+            }
+        );
+        assert_eq!(Sift::from_input("now()".to_string()), call("now", vec![]));
+
+        match Sift::from_input("billing.tax(total, 2)".to_string()) {
+            Sift::Call(call) => {
+                assert_eq!(call.schema, Some("billing".to_string()));
+                assert_eq!(call.name, "tax");
+                assert_eq!(call.args.len(), 2);
+            }
+            other => panic!("Expected call, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn join_words_are_function_names() {
+        assert_eq!(
+            Sift::from_input("left(name, 3)".to_string()),
+            call("left", vec![ident("name"), Sift::Literal(Nutrients::Int(3))])
+        );
+        assert_eq!(
+            Sift::from_input("RIGHT(code, 2)".to_string()),
+            call("RIGHT", vec![ident("code"), Sift::Literal(Nutrients::Int(2))])
+        );
+    }
+
+    #[test]
+    fn aggregates_keep_their_node_with_many_args_and_order_by() {
+        assert!(matches!(Sift::from_input("COUNT(x)".to_string()), Sift::Aggregate(_)));
+        assert_eq!(Sift::from_input("count(DISTINCT a, b)".to_string()), Sift::Aggregate(Aggregate {
+            func: AggregateFn::Count,
+            distinct: true,
+            args: vec![ident("a"), ident("b")],
+            ranks: vec![],
+            filter: None,
+        }));
+
+        match Sift::from_input("SUM(x ORDER BY y)".to_string()) {
+            Sift::Aggregate(aggregate) => {
+                assert_eq!(aggregate.func, AggregateFn::Sum);
+                assert_eq!(aggregate.args, vec![ident("x")]);
+                assert_eq!(aggregate.ranks.len(), 1);
+            }
+            other => panic!("Expected aggregate, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn distinct_order_by_and_filter_inside_call() {
+        let sift = Sift::from_input("string_agg(DISTINCT name, ',' ORDER BY name) FILTER (WHERE active)".to_string());
+
+        assert_eq!(sift, Sift::Call(Call {
+            schema: None,
+            name: "string_agg".to_string(),
+            distinct: true,
+            args: vec![ident("name"), Sift::Literal(Nutrients::Str(",".to_string()))],
+            ranks: vec![Rank { sift: ident("name"), direction: None, nulls: None }],
+            filter: Some(Box::new(ident("active"))),
+        }));
+
+        assert!(matches!(
+            Sift::from_input("array_agg(x) OVER (PARTITION BY y)".to_string()),
+            Sift::Windowed { over: Over::Window(_), .. }
+        ));
+    }
+
+    #[test]
+    fn cast_and_postfix_cast() {
+        assert_eq!(
+            Sift::from_input("CAST(price AS TEXT)".to_string()),
+            Sift::Cast { sift: Box::new(ident("price")), fauna: Fauna::Text }
+        );
+
+        // :: tem precedência maior que o menos unário
+        assert_eq!(
+            Sift::from_input("-qty::int".to_string()),
+            Sift::Unary {
+                op: UnaryOp::Neg,
+                sift: Box::new(Sift::Cast { sift: Box::new(ident("qty")), fauna: Fauna::Int }),
+            }
+        );
+
+        assert_eq!(
+            Sift::from_input("flag::int::boolean".to_string()),
+            Sift::Cast {
+                sift: Box::new(Sift::Cast { sift: Box::new(ident("flag")), fauna: Fauna::Int }),
+                fauna: Fauna::Bool,
+            }
+        );
+    }
+}
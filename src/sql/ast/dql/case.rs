@@ -0,0 +1,106 @@
+use pest::iterators::Pair;
+use serde::{Deserialize, Serialize};
+use crate::sql::ast::dql::sift::Sift;
+use crate::sql::parser::parser::Rule;
+
+// CASE simples (com operando) ou pesquisado (sem operando)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Case {
+    pub operand: Option<Box<Sift>>,
+    pub branches: Vec<CaseWhen>,
+    pub otherwise: Option<Box<Sift>>, // ELSE
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CaseWhen {
+    pub when: Sift,
+    pub then: Sift,
+}
+
+impl Case {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::case);
+
+        let mut case = Case { operand: None, branches: Vec::new(), otherwise: None };
+
+        for inner_pair in pair.into_inner() {
+            match inner_pair.as_rule() {
+                Rule::sift => case.operand = Some(Box::new(Sift::from_pair(inner_pair))),
+                Rule::case_when => {
+                    let mut inner = inner_pair.into_inner();
+                    let when = Sift::from_pair(inner.next().expect("WHEN deve ter condição"));
+                    let then = Sift::from_pair(inner.next().expect("WHEN deve ter THEN"));
+                    case.branches.push(CaseWhen { when, then });
+                }
+                Rule::case_else => {
+                    let sift_pair = inner_pair.into_inner().next().unwrap();
+                    case.otherwise = Some(Box::new(Sift::from_pair(sift_pair)));
+                }
+                _ => panic!("Unexpected rule in CASE: {:?}", inner_pair.as_rule()),
+            }
+        }
+
+        case
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sql::ast::dml::nutrients::Nutrients;
+    use crate::sql::ast::dql::case::{Case, CaseWhen};
+    use crate::sql::ast::dql::ident::Ident;
+    use crate::sql::ast::dql::op::Op;
+    use crate::sql::ast::dql::sift::Sift;
+
+    fn int(value: i64) -> Sift {
+        Sift::Literal(Nutrients::Int(value))
+    }
+
+    fn string(value: &str) -> Sift {
+        Sift::Literal(Nutrients::Str(value.to_string()))
+    }
+
+    #[test]
+    fn searched_case() {
+        let sift = Sift::from_input("CASE WHEN qty > 10 THEN 'bulk' WHEN qty > 0 THEN 'retail' ELSE 'none' END".to_string());
+
+        match sift {
+            Sift::Case(Case { operand, branches, otherwise }) => {
+                assert_eq!(operand, None);
+                assert_eq!(branches.len(), 2);
+                assert_eq!(branches[0], CaseWhen {
+                    when: Sift::Comparison {
+                        left: Box::new(Sift::Ident(Ident::new("qty"))),
+                        op: Op::Gt,
+                        right: Box::new(int(10)),
+                    },
+                    then: string("bulk"),
+                });
+                assert_eq!(otherwise, Some(Box::new(string("none"))));
+            }
+            other => panic!("Expected CASE, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn simple_case_without_else() {
+        let sift = Sift::from_input("case status when 1 then 'open' when 2 then 'closed' end".to_string());
+
+        assert_eq!(sift, Sift::Case(Case {
+            operand: Some(Box::new(Sift::Ident(Ident::new("status")))),
+            branches: vec![
+                CaseWhen { when: int(1), then: string("open") },
+                CaseWhen { when: int(2), then: string("closed") },
+            ],
+            otherwise: None,
+        }));
+    }
+
+    #[test]
+    fn case_is_an_ordinary_operand() {
+        match Sift::from_input("1 + CASE WHEN a THEN 1 ELSE 0 END".to_string()) {
+            Sift::Binary { right, .. } => assert!(matches!(*right, Sift::Case(_))),
+            other => panic!("Expected binary expression, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use pest::iterators::Pair;
+use serde::{Deserialize, Serialize};
+use crate::sql::ast::dql::sift::Sift;
+use crate::sql::parser::parser::Rule;
+
+// Item de GROUP BY
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Cluster {
+    Sift(Sift),
+    Rollup(Vec<Vec<Sift>>),
+    Cube(Vec<Vec<Sift>>),
+    GroupingSets(Vec<Vec<Sift>>),
+}
+
+impl Cluster {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::cluster);
+
+        let inner_pair = pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::sift => Cluster::Sift(Sift::from_pair(inner_pair)),
+            Rule::rollup => Cluster::Rollup(Cluster::sets(inner_pair)),
+            Rule::cube => Cluster::Cube(Cluster::sets(inner_pair)),
+            Rule::grouping_sets => Cluster::GroupingSets(Cluster::sets(inner_pair)),
+            _ => panic!("Unexpected rule in GROUP BY: {:?}", inner_pair.as_rule()),
+        }
+    }
+
+    // Lista de GROUP BY
+    pub fn from_group_by(pair: Pair<Rule>) -> Vec<Self> {
+        assert_eq!(pair.as_rule(), Rule::group_by);
+
+        pair.into_inner().map(Cluster::from_pair).collect()
+    }
+
+    fn sets(pair: Pair<Rule>) -> Vec<Vec<Sift>> {
+        pair.into_inner()
+            .map(|set| set.into_inner().map(Sift::from_pair).collect())
+            .collect()
+    }
+}
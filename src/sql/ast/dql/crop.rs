@@ -0,0 +1,44 @@
+use pest::iterators::Pair;
+use serde::{Deserialize, Serialize};
+use crate::sql::parser::parser::Rule;
+
+// Paginação: LIMIT/OFFSET ou OFFSET ... FETCH FIRST n ROWS ONLY
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Crop {
+    pub limit: Option<Limit>,
+    pub offset: Option<u64>,
+    pub fetch: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Limit {
+    Count(u64),
+    All, // LIMIT ALL: sem limite, mas escrito no comando
+}
+
+impl Crop {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::crop);
+
+        let mut crop = Crop { limit: None, offset: None, fetch: None };
+
+        for inner_pair in pair.into_inner() {
+            // Tree já recusa números fora de i64, então o parse não falha aqui
+            let count = inner_pair
+                .clone()
+                .into_inner()
+                .find(|n| n.as_rule() == Rule::number)
+                .map(|n| n.as_str().parse::<u64>().expect("Número deve ser válido"));
+
+            match inner_pair.as_rule() {
+                Rule::limit => crop.limit = Some(count.map_or(Limit::All, Limit::Count)),
+                Rule::offset => crop.offset = count,
+                // FETCH FIRST ROW ONLY sem quantidade equivale a uma linha
+                Rule::fetch => crop.fetch = Some(count.unwrap_or(1)),
+                _ => panic!("Unexpected rule in crop: {:?}", inner_pair.as_rule()),
+            }
+        }
+
+        crop
+    }
+}
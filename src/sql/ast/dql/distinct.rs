@@ -0,0 +1,29 @@
+use pest::iterators::Pair;
+use serde::{Deserialize, Serialize};
+use crate::sql::ast::dql::sift::Sift;
+use crate::sql::parser::parser::Rule;
+
+// Qualificador de SELECT
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Distinct {
+    All,
+    Distinct,
+    On(Vec<Sift>), // DISTINCT ON (...)
+}
+
+impl Distinct {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::distinction);
+
+        let upper = pair.as_str().to_uppercase();
+        let sifts: Vec<Sift> = pair.into_inner().map(Sift::from_pair).collect();
+
+        if upper.starts_with("ALL") {
+            Distinct::All
+        } else if sifts.is_empty() {
+            Distinct::Distinct
+        } else {
+            Distinct::On(sifts)
+        }
+    }
+}
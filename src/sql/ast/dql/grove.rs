@@ -0,0 +1,117 @@
+use pest::iterators::Pair;
+use serde::{Deserialize, Serialize};
+use crate::sql::ast::dql::DQL;
+use crate::sql::parser::parser::{Rule, SQLParser};
+
+// Cláusula WITH [RECURSIVE]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Grove {
+    pub recursive: bool,
+    pub saplings: Vec<Sapling>,
+}
+
+// CTE nomeada: nome [(colunas)] AS [[NOT] MATERIALIZED] (consulta)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Sapling {
+    pub bud: String,
+    pub piths: Vec<String>,
+    pub materialized: Option<bool>, // Some(false) é NOT MATERIALIZED
+    pub dql: Box<DQL>,
+}
+
+impl Grove {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::grove);
+
+        let mut recursive = false;
+        let mut saplings = Vec::new();
+
+        for inner_pair in pair.into_inner() {
+            match inner_pair.as_rule() {
+                Rule::recursive => recursive = true,
+                Rule::sapling => saplings.push(Sapling::from_pair(inner_pair)),
+                _ => panic!("Unexpected rule in WITH: {:?}", inner_pair.as_rule()),
+            }
+        }
+
+        Self { recursive, saplings }
+    }
+
+    pub fn from_input(input: String) -> Self {
+        let pair = SQLParser::parse_whole(Rule::grove, &input);
+        Grove::from_pair(pair)
+    }
+}
+
+impl Sapling {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::sapling);
+
+        let mut inner = pair.into_inner();
+
+        // Primeiro é o nome da CTE
+        let bud = inner
+            .next()
+            .expect("CTE deve ter nome")
+            .as_str()
+            .to_string();
+
+        let mut piths = Vec::new();
+        let mut materialized = None;
+        let mut dql = None;
+
+        for inner_pair in inner {
+            match inner_pair.as_rule() {
+                Rule::ident => piths.push(inner_pair.as_str().to_string()),
+                Rule::materialization => {
+                    materialized = Some(!inner_pair.as_str().to_uppercase().starts_with("NOT"));
+                }
+                Rule::DQL => dql = Some(DQL::from_pair(inner_pair)),
+                _ => panic!("Unexpected rule in CTE: {:?}", inner_pair.as_rule()),
+            }
+        }
+
+        Self {
+            bud,
+            piths,
+            materialized,
+            dql: Box::new(dql.expect("CTE deve ter consulta")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sql::ast::dql::grove::Grove;
+    use crate::sql::ast::dql::DQL;
+
+    #[test]
+    fn recursive_cte_with_columns() {
+        let grove = Grove::from_input(
+            "WITH RECURSIVE tree (id, parent_id) AS (\
+                SELECT id, parent_id FROM Node WHERE parent_id = 0 \
+                UNION ALL \
+                SELECT id, parent_id FROM Node JOIN tree USING (parent_id)\
+            )".to_string(),
+        );
+
+        assert!(grove.recursive);
+        assert_eq!(grove.saplings.len(), 1);
+        assert_eq!(grove.saplings[0].bud, "tree");
+        assert_eq!(grove.saplings[0].piths, vec!["id", "parent_id"]);
+        assert_eq!(grove.saplings[0].materialized, None);
+        assert!(matches!(*grove.saplings[0].dql, DQL::Bouquet(_)));
+    }
+
+    #[test]
+    fn materialization_hints() {
+        let grove = Grove::from_input(
+            "WITH a AS MATERIALIZED (SELECT 1), b AS NOT MATERIALIZED (SELECT * FROM a)".to_string(),
+        );
+
+        assert!(!grove.recursive);
+        assert_eq!(grove.saplings[0].materialized, Some(true));
+        assert_eq!(grove.saplings[1].materialized, Some(false));
+        assert!(matches!(*grove.saplings[1].dql, DQL::Sample(_)));
+    }
+}
@@ -0,0 +1,110 @@
+use pest::iterators::Pair;
+use serde::{Deserialize, Serialize};
+use crate::sql::parser::parser::{Rule, SQLParser};
+
+// Referência a coluna, opcionalmente qualificada: col, tabela.col, schema.tabela.col
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Ident {
+    pub schema: Option<String>,
+    pub bud: Option<String>, // tabela ou alias
+    pub vein: String,
+}
+
+impl Ident {
+    pub fn new(vein: &str) -> Self {
+        Self { schema: None, bud: None, vein: vein.to_string() }
+    }
+
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::compound_ident);
+
+        let mut names: Vec<String> = pair.into_inner().map(|p| p.as_str().to_string()).collect();
+
+        // Da direita para a esquerda: coluna, tabela e schema
+        let vein = names.pop().expect("Identificador deve ter nome da coluna");
+        let bud = names.pop();
+        let schema = names.pop();
+
+        Self { schema, bud, vein }
+    }
+
+    pub fn from_input(input: String) -> Self {
+        let pair = SQLParser::parse_whole(Rule::compound_ident, &input);
+        Ident::from_pair(pair)
+    }
+
+    // Partes presentes, na ordem em que aparecem no SQL
+    pub fn parts(&self) -> Vec<&str> {
+        [self.schema.as_deref(), self.bud.as_deref(), Some(self.vein.as_str())]
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+
+    pub fn is_qualified(&self) -> bool {
+        self.bud.is_some()
+    }
+
+    // Compara sem diferenciar maiúsculas; qualificadores só contam quando presentes nos dois lados
+    pub fn matches(&self, other: &Ident) -> bool {
+        fn same(a: &Option<String>, b: &Option<String>) -> bool {
+            match (a, b) {
+                (Some(a), Some(b)) => a.eq_ignore_ascii_case(b),
+                _ => true,
+            }
+        }
+
+        self.vein.eq_ignore_ascii_case(&other.vein) && same(&self.bud, &other.bud) && same(&self.schema, &other.schema)
+    }
+}
+
+// Igualdade com a forma textual: Ident == "u.id"
+impl PartialEq<&str> for Ident {
+    fn eq(&self, other: &&str) -> bool {
+        self.parts().join(".") == *other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sql::ast::dql::ident::Ident;
+    use crate::sql::ast::dql::op::Op;
+    use crate::sql::ast::dql::sift::Sift;
+
+    #[test]
+    fn split_into_schema_table_and_column() {
+        assert_eq!(Ident::from_input("id".to_string()), Ident::new("id"));
+
+        let ident = Ident::from_input("u.id".to_string());
+        assert_eq!(ident.bud, Some("u".to_string()));
+        assert_eq!(ident.vein, "id");
+        assert!(ident.is_qualified());
+
+        let ident = Ident::from_input("public.users.id".to_string());
+        assert_eq!(ident.schema, Some("public".to_string()));
+        assert_eq!(ident.parts(), vec!["public", "users", "id"]);
+        assert_eq!(ident, "public.users.id");
+    }
+
+    #[test]
+    fn matches_ignores_missing_qualifiers_and_case() {
+        let full = Ident::from_input("public.users.id".to_string());
+
+        assert!(full.matches(&Ident::new("ID")));
+        assert!(full.matches(&Ident::from_input("Users.id".to_string())));
+        assert!(!full.matches(&Ident::from_input("orders.id".to_string())));
+        assert!(!full.matches(&Ident::new("user_id")));
+    }
+
+    #[test]
+    fn qualified_columns_in_expressions() {
+        assert_eq!(
+            Sift::from_input("u.id = o.user_id".to_string()),
+            Sift::Comparison {
+                left: Box::new(Sift::Ident(Ident::from_input("u.id".to_string()))),
+                op: Op::Eq,
+                right: Box::new(Sift::Ident(Ident::from_input("o.user_id".to_string()))),
+            }
+        );
+    }
+}
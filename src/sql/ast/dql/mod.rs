@@ -1,16 +1,32 @@
 pub mod sample;
 pub mod twigs;
 pub mod sift;
-mod op;
+pub mod rank;
+pub mod crop;
+pub mod root;
+pub mod aggregate;
+pub mod cluster;
+pub mod distinct;
+pub mod bouquet;
+pub mod grove;
+pub mod window;
+pub mod case;
+pub mod call;
+pub mod ident;
+pub mod op;
 
 use pest::iterators::Pair;
 use serde::{Deserialize, Serialize};
+use crate::sql::ast::dql::bouquet::{Blend, Bouquet};
 use crate::sql::ast::dql::sample::Sample;
 use crate::sql::parser::parser::Rule;
 
+// SELECT é o caso comum; não vale a pena colocá-lo em Box
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum DQL {
     Sample(Sample),
+    Bouquet(Bouquet),
 }
 
 impl DQL {
@@ -23,6 +39,14 @@ impl DQL {
                 let select = Sample::from_pair(inner_pair);
                 DQL::Sample(select)
             }
+            Rule::bouquet => {
+                let bouquet = Bouquet::from_pair(inner_pair);
+                match bouquet.blend {
+                    Blend::Sample(sample) if bouquet.is_bare() => DQL::Sample(*sample),
+                    Blend::Bouquet(inner) if bouquet.is_bare() => DQL::Bouquet(*inner),
+                    _ => DQL::Bouquet(bouquet),
+                }
+            }
             _ => panic!("Unexpected rule: {:?}", inner_pair.as_rule()),
         }
     }
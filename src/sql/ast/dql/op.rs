@@ -10,6 +10,36 @@ pub enum Op {
     Gte,
 }
 
+// Operadores aritméticos, bit a bit e de concatenação
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Mod,
+    Concat, // ||
+    BitAnd,
+    BitOr,
+    BitXor, // ^ / #
+    ShiftLeft,
+    ShiftRight,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UnaryOp {
+    Not,
+    Neg,
+    Plus,
+    BitNot, // ~
+}
+
+// Quantificador de comparação com subconsulta: x > ALL (SELECT ...)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Quantifier {
+    Any, // ANY / SOME
+    All,
+}
 
 impl Op {
     pub fn from_input(s: &str) -> Self {
@@ -24,3 +54,44 @@ impl Op {
         }
     }
 }
+
+impl Quantifier {
+    pub fn from_input(s: &str) -> Self {
+        match s.to_uppercase().as_str() {
+            "ANY" | "SOME" => Quantifier::Any,
+            "ALL" => Quantifier::All,
+            _ => panic!("Unknown quantifier: {}", s),
+        }
+    }
+}
+
+impl BinaryOp {
+    pub fn from_input(s: &str) -> Self {
+        match s {
+            "+" => BinaryOp::Add,
+            "-" => BinaryOp::Sub,
+            "*" => BinaryOp::Mul,
+            "/" => BinaryOp::Div,
+            "%" => BinaryOp::Mod,
+            "||" => BinaryOp::Concat,
+            "&" => BinaryOp::BitAnd,
+            "|" => BinaryOp::BitOr,
+            "^" | "#" => BinaryOp::BitXor,
+            "<<" => BinaryOp::ShiftLeft,
+            ">>" => BinaryOp::ShiftRight,
+            _ => panic!("Unknown binary operator: {}", s),
+        }
+    }
+}
+
+impl UnaryOp {
+    pub fn from_input(s: &str) -> Self {
+        match s.to_uppercase().as_str() {
+            "NOT" => UnaryOp::Not,
+            "-" => UnaryOp::Neg,
+            "+" => UnaryOp::Plus,
+            "~" => UnaryOp::BitNot,
+            _ => panic!("Unknown unary operator: {}", s),
+        }
+    }
+}
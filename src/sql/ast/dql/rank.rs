@@ -0,0 +1,78 @@
+use pest::iterators::Pair;
+use serde::{Deserialize, Serialize};
+use crate::sql::ast::dql::sift::Sift;
+use crate::sql::parser::parser::Rule;
+
+// Item de ORDER BY
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rank {
+    pub sift: Sift,
+    pub direction: Option<Direction>,
+    pub nulls: Option<Nulls>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Direction {
+    Asc,
+    Desc,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Nulls {
+    First,
+    Last,
+}
+
+impl Rank {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::rank);
+
+        let mut inner = pair.into_inner();
+        let sift = Sift::from_pair(inner.next().expect("ORDER BY deve ter uma expressão"));
+
+        let mut direction = None;
+        let mut nulls = None;
+
+        for inner_pair in inner {
+            match inner_pair.as_rule() {
+                Rule::direction => direction = Some(Direction::from_pair(inner_pair)),
+                Rule::nulls => nulls = Some(Nulls::from_pair(inner_pair)),
+                _ => panic!("Unexpected rule in ORDER BY: {:?}", inner_pair.as_rule()),
+            }
+        }
+
+        Self { sift, direction, nulls }
+    }
+
+    // Lista de ORDER BY
+    pub fn from_order_by(pair: Pair<Rule>) -> Vec<Self> {
+        assert_eq!(pair.as_rule(), Rule::order_by);
+
+        pair.into_inner().map(Rank::from_pair).collect()
+    }
+}
+
+impl Direction {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::direction);
+
+        match pair.as_str().to_uppercase().as_str() {
+            "ASC" => Direction::Asc,
+            "DESC" => Direction::Desc,
+            _ => panic!("Unknown sort direction: {}", pair.as_str()),
+        }
+    }
+}
+
+impl Nulls {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::nulls);
+
+        let upper = pair.as_str().to_uppercase();
+        if upper.ends_with("FIRST") {
+            Nulls::First
+        } else {
+            Nulls::Last
+        }
+    }
+}
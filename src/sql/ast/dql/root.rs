@@ -0,0 +1,251 @@
+use pest::iterators::Pair;
+use serde::{Deserialize, Serialize};
+use crate::sql::ast::dql::sift::Sift;
+use crate::sql::ast::dql::DQL;
+use crate::sql::parser::parser::{Rule, SQLParser};
+
+// Origem de linhas da cláusula FROM
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Root {
+    Bud {
+        bud: String,
+        alias: Option<String>,
+    },
+    Sample {
+        sample: Box<DQL>,
+        alias: Option<String>,
+    },
+    Join {
+        left: Box<Root>,
+        kind: JoinKind,
+        natural: bool,
+        right: Box<Root>,
+        constraint: Option<JoinConstraint>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum JoinKind {
+    Inner,
+    Left,
+    Right,
+    Full,
+    Cross,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum JoinConstraint {
+    On(Sift),
+    Using(Vec<String>),
+}
+
+impl Root {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        match pair.as_rule() {
+            Rule::root => {
+                let mut inner = pair.into_inner();
+                let mut root = Root::from_pair(inner.next().unwrap());
+
+                // Junções encadeiam à esquerda: a JOIN b JOIN c = (a JOIN b) JOIN c
+                for join_pair in inner {
+                    root = Root::join(root, join_pair);
+                }
+                root
+            }
+            Rule::rootlet => {
+                let mut inner = pair.into_inner();
+                let first = inner.next().unwrap();
+
+                match first.as_rule() {
+                    Rule::root => Root::from_pair(first),
+                    Rule::DQL => {
+                        let alias = inner
+                            .next()
+                            .map(|a| a.into_inner().next().unwrap().as_str().to_string());
+                        Root::Sample {
+                            sample: Box::new(DQL::from_pair(first)),
+                            alias,
+                        }
+                    }
+                    Rule::bud => {
+                        let alias = inner
+                            .next()
+                            .map(|a| a.into_inner().next().unwrap().as_str().to_string());
+                        Root::Bud {
+                            bud: first.as_str().to_string(),
+                            alias,
+                        }
+                    }
+                    _ => panic!("Unexpected rule in FROM: {:?}", first.as_rule()),
+                }
+            }
+            _ => panic!("Unexpected rule in FROM: {:?}", pair.as_rule()),
+        }
+    }
+
+    // Lista de FROM
+    pub fn from_roots(pair: Pair<Rule>) -> Vec<Self> {
+        assert_eq!(pair.as_rule(), Rule::roots);
+
+        pair.into_inner().map(Root::from_pair).collect()
+    }
+
+    pub fn from_input(input: String) -> Self {
+        let pair = SQLParser::parse_whole(Rule::root, &input);
+        Root::from_pair(pair)
+    }
+
+    fn join(left: Root, pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::join);
+
+        let mut kind = JoinKind::Inner;
+        let mut natural = false;
+        let mut right = None;
+        let mut constraint = None;
+
+        for inner_pair in pair.into_inner() {
+            match inner_pair.as_rule() {
+                Rule::cross => kind = JoinKind::Cross,
+                Rule::natural => natural = true,
+                Rule::join_kind => kind = JoinKind::from_pair(inner_pair),
+                Rule::rootlet => right = Some(Root::from_pair(inner_pair)),
+                Rule::join_on => {
+                    let sift = Sift::from_pair(inner_pair.into_inner().next().unwrap());
+                    constraint = Some(JoinConstraint::On(sift));
+                }
+                Rule::join_using => {
+                    let piths = inner_pair.into_inner().map(|p| p.as_str().to_string()).collect();
+                    constraint = Some(JoinConstraint::Using(piths));
+                }
+                _ => panic!("Unexpected rule in JOIN: {:?}", inner_pair.as_rule()),
+            }
+        }
+
+        Root::Join {
+            left: Box::new(left),
+            kind,
+            natural,
+            right: Box::new(right.expect("JOIN deve ter tabela")),
+            constraint,
+        }
+    }
+}
+
+impl JoinKind {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::join_kind);
+
+        let upper = pair.as_str().to_uppercase();
+        match upper.split_whitespace().next().unwrap() {
+            "INNER" => JoinKind::Inner,
+            "LEFT" => JoinKind::Left,
+            "RIGHT" => JoinKind::Right,
+            "FULL" => JoinKind::Full,
+            _ => panic!("Unknown join kind: {}", pair.as_str()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sql::ast::dql::root::{JoinConstraint, JoinKind, Root};
+    use crate::sql::ast::dql::DQL;
+    use crate::sql::parser::tree::Tree;
+
+    fn bud(name: &str, alias: Option<&str>) -> Root {
+        Root::Bud { bud: name.to_string(), alias: alias.map(|a| a.to_string()) }
+    }
+
+    #[test]
+    fn table_with_alias() {
+        assert_eq!(Root::from_input("Account AS a".to_string()), bud("Account", Some("a")));
+        assert_eq!(Root::from_input("Account a".to_string()), bud("Account", Some("a")));
+        assert_eq!(Root::from_input("Account".to_string()), bud("Account", None));
+    }
+
+    #[test]
+    fn joins_chain_to_the_left() {
+        let root = Root::from_input(
+            "Account a LEFT OUTER JOIN Orders o ON id = account_id CROSS JOIN Region".to_string(),
+        );
+
+        match root {
+            Root::Join { left, kind: JoinKind::Cross, natural: false, right, constraint: None } => {
+                assert_eq!(*right, bud("Region", None));
+                match *left {
+                    Root::Join { left, kind, right, constraint, .. } => {
+                        assert_eq!(*left, bud("Account", Some("a")));
+                        assert_eq!(kind, JoinKind::Left);
+                        assert_eq!(*right, bud("Orders", Some("o")));
+                        assert!(matches!(constraint, Some(JoinConstraint::On(_))));
+                    }
+                    other => panic!("Expected inner join, got {:?}", other),
+                }
+            }
+            other => panic!("Expected CROSS JOIN, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn natural_and_using_joins() {
+        let root = Root::from_input("Account NATURAL JOIN Profile".to_string());
+        assert!(matches!(root, Root::Join { kind: JoinKind::Inner, natural: true, constraint: None, .. }));
+
+        let root = Root::from_input("Account FULL JOIN (Orders JOIN Item USING (order_id)) USING (account_id)".to_string());
+        match root {
+            Root::Join { kind: JoinKind::Full, right, constraint, .. } => {
+                assert_eq!(constraint, Some(JoinConstraint::Using(vec!["account_id".to_string()])));
+                assert!(matches!(*right, Root::Join { kind: JoinKind::Inner, .. }));
+            }
+            other => panic!("Expected FULL JOIN, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn constraints_must_match_the_join_kind() {
+        for input in [
+            "SELECT * FROM a CROSS JOIN b ON a.id = b.id",
+            "SELECT * FROM a NATURAL JOIN b USING (id)",
+            "SELECT * FROM a NATURAL LEFT JOIN b ON TRUE",
+            "SELECT * FROM a JOIN b",
+            "SELECT * FROM a LEFT JOIN b",
+            "SELECT * FROM a NATURAL CROSS JOIN b",
+        ] {
+            assert!(Tree::try_parse(input.to_string()).is_err(), "{}", input);
+        }
+
+        let root = Root::from_input("a NATURAL LEFT OUTER JOIN b".to_string());
+        assert!(matches!(root, Root::Join { kind: JoinKind::Left, natural: true, constraint: None, .. }));
+    }
+
+    #[test]
+    fn derived_table() {
+        match Root::from_input("(SELECT id FROM Account WHERE active = TRUE) AS live JOIN Orders USING (id)".to_string()) {
+            Root::Join { left, .. } => match *left {
+                Root::Sample { sample, alias } => {
+                    assert_eq!(alias, Some("live".to_string()));
+                    assert!(matches!(*sample, DQL::Sample(ref sample) if sample.gate.is_some()));
+                }
+                other => panic!("Expected derived table, got {:?}", other),
+            },
+            other => panic!("Expected JOIN, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn derived_table_with_set_operation() {
+        match Root::from_input("(SELECT a FROM x UNION SELECT a FROM y) AS u".to_string()) {
+            Root::Sample { sample, alias } => {
+                assert_eq!(alias, Some("u".to_string()));
+                assert!(matches!(*sample, DQL::Bouquet(_)));
+            }
+            other => panic!("Expected derived table, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn schema_qualified_tables() {
+        assert_eq!(Root::from_input("public.Account a".to_string()), bud("public.Account", Some("a")));
+        assert_eq!(Root::from_input("shop.public.Account".to_string()), bud("shop.public.Account", None));
+    }
+}
@@ -1,52 +1,249 @@
 use pest::iterators::Pair;
 use serde::{Deserialize, Serialize};
+use crate::sql::ast::dql::cluster::Cluster;
+use crate::sql::ast::dql::crop::Crop;
+use crate::sql::ast::dql::distinct::Distinct;
+use crate::sql::ast::dql::grove::Grove;
+use crate::sql::ast::dql::rank::Rank;
+use crate::sql::ast::dql::root::Root;
 use crate::sql::ast::dql::twigs::Twigs;
-use crate::sql::parser::parser::Rule;
+use crate::sql::ast::dql::window::NamedWindow;
+use crate::sql::parser::parser::{Rule, SQLParser};
 use crate::sql::ast::dql::sift::Sift;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Sample {
-    pub bud: String,
+    pub grove: Option<Grove>,
+    pub distinct: Option<Distinct>,
     pub piths: Twigs,
+    pub roots: Vec<Root>,
     pub gate: Option<Sift>,
+    pub clusters: Vec<Cluster>,
+    pub having: Option<Sift>,
+    pub windows: Vec<NamedWindow>,
+    pub ranks: Vec<Rank>,
+    pub crop: Option<Crop>,
 }
 
 impl Sample {
     pub fn from_pair(pair: Pair<Rule>) -> Self {
-        assert_eq!(pair.as_rule(), Rule::sample);
+        // blend_sample é o SELECT operando de UNION/INTERSECT/EXCEPT
+        assert!(matches!(pair.as_rule(), Rule::sample | Rule::blend_sample));
 
-        let mut inner = pair.into_inner();
+        let mut inner = pair.into_inner().peekable();
 
-        // Primeiro são as colunas
-        let columns_pair = inner
+        // WITH opcional antes do comando
+        let grove = inner.next_if(|p| p.as_rule() == Rule::grove).map(Grove::from_pair);
+
+        // Depois vem o DISTINCT opcional, depois as colunas
+        let mut columns_pair = inner
             .next()
             .expect("SELECT deve ter colunas");
 
-        let columns = Twigs::from_pair(columns_pair);
-
-        // Segundo é o nome da tabela
-        let table = inner
-            .next()
-            .expect("SELECT deve ter nome da tabela")
-            .as_str()
-            .to_string();
-
-        // Terceiro é opcional: WHERE clause
-        let gate = if let Some(where_pair) = inner.next() {
-            match where_pair.as_rule() {
-                Rule::gate => {
-                    Some(Sift::from_pair(where_pair))
-                }
-                _ => panic!("Unexpected rule after table: {:?}", where_pair.as_rule()),
-            }
+        let distinct = if columns_pair.as_rule() == Rule::distinction {
+            let distinct = Distinct::from_pair(columns_pair);
+            columns_pair = inner.next().expect("SELECT deve ter colunas");
+            Some(distinct)
         } else {
             None
         };
 
+        let columns = Twigs::from_pair(columns_pair);
+
+        // Demais são opcionais: FROM, WHERE, GROUP BY, HAVING, WINDOW, ORDER BY e paginação
+        let mut roots = Vec::new();
+        let mut gate = None;
+        let mut clusters = Vec::new();
+        let mut having = None;
+        let mut windows = Vec::new();
+        let mut ranks = Vec::new();
+        let mut crop = None;
+
+        for inner_pair in inner {
+            match inner_pair.as_rule() {
+                Rule::roots => roots = Root::from_roots(inner_pair),
+                Rule::gate => gate = Some(Sift::from_pair(inner_pair)),
+                Rule::group_by => clusters = Cluster::from_group_by(inner_pair),
+                Rule::having => having = Some(Sift::from_pair(inner_pair.into_inner().next().unwrap())),
+                Rule::window_clause => windows = NamedWindow::from_window_clause(inner_pair),
+                Rule::order_by => ranks = Rank::from_order_by(inner_pair),
+                Rule::crop => crop = Some(Crop::from_pair(inner_pair)),
+                _ => panic!("Unexpected rule after projection: {:?}", inner_pair.as_rule()),
+            }
+        }
+
         Self {
+            grove,
+            distinct,
             piths: columns,
-            bud: table,
+            roots,
             gate,
+            clusters,
+            having,
+            windows,
+            ranks,
+            crop,
+        }
+    }
+
+    pub fn from_input(input: String) -> Self {
+        let pair = SQLParser::parse_whole(Rule::sample, &input);
+        Sample::from_pair(pair)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sql::ast::dml::nutrients::Nutrients;
+    use crate::sql::ast::dql::aggregate::{Aggregate, AggregateFn};
+    use crate::sql::ast::dql::cluster::Cluster;
+    use crate::sql::ast::dql::crop::{Crop, Limit};
+    use crate::sql::ast::dql::distinct::Distinct;
+    use crate::sql::ast::dql::ident::Ident;
+    use crate::sql::ast::dql::rank::{Direction, Nulls, Rank};
+    use crate::sql::ast::dql::sample::Sample;
+    use crate::sql::ast::dql::sift::Sift;
+    use crate::sql::ast::dql::twigs::{Twig, Twigs};
+    use crate::sql::parser::tree::Tree;
+
+    #[test]
+    fn from_is_optional() {
+        let sample = Sample::from_input("SELECT 1".to_string());
+        assert!(sample.roots.is_empty());
+
+        let sample = Sample::from_input("SELECT * FROM Account a, Region r JOIN Zone z USING (zone_id)".to_string());
+        assert_eq!(sample.roots.len(), 2);
+    }
+
+    #[test]
+    fn distinct_qualifiers() {
+        assert_eq!(Sample::from_input("SELECT country FROM Users".to_string()).distinct, None);
+        assert_eq!(Sample::from_input("SELECT ALL country FROM Users".to_string()).distinct, Some(Distinct::All));
+        assert_eq!(Sample::from_input("SELECT DISTINCT country FROM Users".to_string()).distinct, Some(Distinct::Distinct));
+
+        let sample = Sample::from_input("SELECT DISTINCT ON (user_id, day) user_id, total FROM Orders ORDER BY user_id".to_string());
+        assert_eq!(sample.distinct, Some(Distinct::On(vec![
+            Sift::Ident(Ident::new("user_id")),
+            Sift::Ident(Ident::new("day")),
+        ])));
+        assert_eq!(sample.piths, Twigs::from_input("user_id, total".to_string()));
+    }
+
+    #[test]
+    fn with_clause_in_front_of_select() {
+        let sample = Sample::from_input("WITH big AS (SELECT * FROM Orders WHERE total > 100) SELECT COUNT(*) FROM big".to_string());
+
+        let grove = sample.grove.expect("SELECT deveria ter WITH");
+        assert_eq!(grove.saplings.len(), 1);
+        assert_eq!(sample.roots.len(), 1);
+    }
+
+    #[test]
+    fn order_by_with_direction_and_nulls() {
+        let input = "SELECT * FROM Product WHERE available = TRUE ORDER BY price DESC NULLS LAST, title".to_string();
+        let sample = Sample::from_input(input);
+
+        assert!(sample.gate.is_some());
+        assert_eq!(sample.ranks, vec![
+            Rank {
+                sift: Sift::Ident(Ident::new("price")),
+                direction: Some(Direction::Desc),
+                nulls: Some(Nulls::Last),
+            },
+            Rank {
+                sift: Sift::Ident(Ident::new("title")),
+                direction: None,
+                nulls: None,
+            },
+        ]);
+        assert_eq!(sample.crop, None);
+    }
+
+    #[test]
+    fn limit_and_offset() {
+        let sample = Sample::from_input("SELECT * FROM Product ORDER BY id ASC LIMIT 20 OFFSET 40".to_string());
+
+        assert_eq!(sample.ranks[0].direction, Some(Direction::Asc));
+        assert_eq!(sample.crop, Some(Crop { limit: Some(Limit::Count(20)), offset: Some(40), fetch: None }));
+    }
+
+    #[test]
+    fn offset_before_limit_and_limit_all() {
+        let sample = Sample::from_input("SELECT * FROM Product OFFSET 5 LIMIT 10".to_string());
+        assert_eq!(sample.crop, Some(Crop { limit: Some(Limit::Count(10)), offset: Some(5), fetch: None }));
+
+        let sample = Sample::from_input("SELECT * FROM Product LIMIT ALL OFFSET 5".to_string());
+        assert_eq!(sample.crop, Some(Crop { limit: Some(Limit::All), offset: Some(5), fetch: None }));
+    }
+
+    #[test]
+    fn limit_out_of_range_is_an_error() {
+        assert!(Tree::try_parse("SELECT a FROM t LIMIT 99999999999999999999".to_string()).is_err());
+        assert!(Tree::try_parse("SELECT a FROM t OFFSET 99999999999999999999 ROWS".to_string()).is_err());
+    }
+
+    #[test]
+    fn offset_fetch_first() {
+        let sample = Sample::from_input("SELECT * FROM Product ORDER BY id OFFSET 40 ROWS FETCH FIRST 20 ROWS ONLY".to_string());
+        assert_eq!(sample.crop, Some(Crop { limit: None, offset: Some(40), fetch: Some(20) }));
+
+        let sample = Sample::from_input("SELECT * FROM Product FETCH NEXT ROW ONLY".to_string());
+        assert_eq!(sample.crop, Some(Crop { limit: None, offset: None, fetch: Some(1) }));
+    }
+
+    #[test]
+    fn group_by_having_and_aggregates() {
+        let input = "SELECT region, COUNT(*), COUNT(DISTINCT city), SUM(total) FILTER (WHERE paid = TRUE) \
+            FROM Orders GROUP BY region HAVING MAX(total) > 100".to_string();
+        let sample = Sample::from_input(input);
+
+        let twigs = match sample.piths {
+            Twigs::Named(twigs) => twigs,
+            Twigs::All => panic!("Expected named projection"),
+        };
+        assert_eq!(twigs[1], Twig::Sift {
+            sift: Sift::Aggregate(Aggregate { func: AggregateFn::Count, distinct: false, args: vec![], ranks: vec![], filter: None }),
+            alias: None,
+        });
+        assert_eq!(twigs[2], Twig::Sift {
+            sift: Sift::Aggregate(Aggregate {
+                func: AggregateFn::Count,
+                distinct: true,
+                args: vec![Sift::Ident(Ident::new("city"))],
+                ranks: vec![],
+                filter: None,
+            }),
+            alias: None,
+        });
+        match &twigs[3] {
+            Twig::Sift { sift: Sift::Aggregate(aggregate), .. } => {
+                assert_eq!(aggregate.func, AggregateFn::Sum);
+                assert!(aggregate.filter.is_some());
+            }
+            other => panic!("Expected SUM, got {:?}", other),
         }
+
+        assert_eq!(sample.clusters, vec![Cluster::Sift(Sift::Ident(Ident::new("region")))]);
+        match sample.having {
+            Some(Sift::Comparison { left, right, .. }) => {
+                assert!(matches!(*left, Sift::Aggregate(Aggregate { func: AggregateFn::Max, .. })));
+                assert_eq!(*right, Sift::Literal(Nutrients::Int(100)));
+            }
+            other => panic!("Expected HAVING comparison, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn rollup_cube_and_grouping_sets() {
+        let sample = Sample::from_input(
+            "SELECT * FROM Sales GROUP BY ROLLUP (region, (city, shop)), CUBE (year), GROUPING SETS ((region), ())".to_string(),
+        );
+
+        let ident = |name: &str| Sift::Ident(Ident::new(name));
+        assert_eq!(sample.clusters, vec![
+            Cluster::Rollup(vec![vec![ident("region")], vec![ident("city"), ident("shop")]]),
+            Cluster::Cube(vec![vec![ident("year")]]),
+            Cluster::GroupingSets(vec![vec![ident("region")], vec![]]),
+        ]);
     }
 }
@@ -1,8 +1,15 @@
 use pest::iterators::Pair;
 use serde::{Deserialize, Serialize};
-use crate::sql::parser::parser::Rule;
+use crate::sql::parser::parser::{Rule, SQLParser};
+use crate::sql::ast::ddl::fauna::Fauna;
 use crate::sql::ast::dml::nutrients::Nutrients;
-use crate::sql::ast::dql::op::Op;
+use crate::sql::ast::dql::aggregate::Aggregate;
+use crate::sql::ast::dql::call::Call;
+use crate::sql::ast::dql::ident::Ident;
+use crate::sql::ast::dql::case::Case;
+use crate::sql::ast::dql::op::{BinaryOp, Op, Quantifier, UnaryOp};
+use crate::sql::ast::dql::DQL;
+use crate::sql::ast::dql::window::{Over, WindowCall};
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Sift {
@@ -13,7 +20,76 @@ pub enum Sift {
         op: Op,
         right: Box<Sift>,
     },
-    Ident(String),
+    Binary {
+        left: Box<Sift>,
+        op: BinaryOp,
+        right: Box<Sift>,
+    },
+    Unary {
+        op: UnaryOp,
+        sift: Box<Sift>,
+    },
+    Quantified {
+        left: Box<Sift>,
+        op: Op,
+        quantifier: Quantifier,
+        sample: Box<DQL>,
+    },
+    InSample {
+        sift: Box<Sift>,
+        sample: Box<DQL>,
+        negated: bool,
+    },
+    InList {
+        sift: Box<Sift>,
+        list: Vec<Sift>,
+        negated: bool,
+    },
+    Like {
+        sift: Box<Sift>,
+        pattern: Box<Sift>,
+        escape: Option<Box<Sift>>,
+        negated: bool,
+        case_insensitive: bool, // ILIKE
+    },
+    SimilarTo {
+        sift: Box<Sift>,
+        pattern: Box<Sift>,
+        escape: Option<Box<Sift>>,
+        negated: bool,
+    },
+    Between {
+        sift: Box<Sift>,
+        low: Box<Sift>,
+        high: Box<Sift>,
+        negated: bool,
+    },
+    IsNull {
+        sift: Box<Sift>,
+        negated: bool,
+    },
+    Exists(Box<DQL>),
+    Sample(Box<DQL>), // subconsulta escalar
+    Ident(Ident),
+    Excluded(String),    // EXCLUDED.col em upserts (Postgres/SQLite)
+    InsertValue(String), // VALUES(col) em ON DUPLICATE KEY UPDATE (MySQL)
+    Default,          // DEFAULT numa linha de VALUES
+    Case(Case),
+    Coalesce(Vec<Sift>),
+    NullIf(Box<Sift>, Box<Sift>),
+    Greatest(Vec<Sift>),
+    Least(Vec<Sift>),
+    Aggregate(Aggregate),
+    Call(Call),
+    Cast {
+        sift: Box<Sift>,
+        fauna: Fauna,
+    },
+    WindowCall(WindowCall),
+    Windowed {
+        sift: Box<Sift>,
+        over: Over,
+    },
     Literal(Nutrients),
     Paren(Box<Sift>),
 }
@@ -32,7 +108,7 @@ impl Sift {
             Rule::or_sift => {
                 let mut inner = pair.into_inner();
                 let mut sift = Sift::from_pair(inner.next().unwrap());
-                while let Some(next_sift) = inner.next() {
+                for next_sift in inner {
                     let right = Sift::from_pair(next_sift);
                     sift = Sift::Or(Box::new(sift), Box::new(right));
                 }
@@ -42,43 +118,229 @@ impl Sift {
                 let mut inner = pair.into_inner();
                 let mut sift = Sift::from_pair(inner.next().unwrap());
 
-                while let Some(next_sift) = inner.next() {
+                for next_sift in inner {
                     let right = Sift::from_pair(next_sift);
                     sift = Sift::And(Box::new(sift), Box::new(right));
                 }
                 sift
             }
+            Rule::not_sift | Rule::unary_sift => {
+                let mut inner = pair.into_inner();
+                let first = inner.next().unwrap();
+
+                match inner.next() {
+                    Some(operand) => Sift::Unary {
+                        op: UnaryOp::from_input(first.as_str()),
+                        sift: Box::new(Sift::from_pair(operand)),
+                    },
+                    None => Sift::from_pair(first),
+                }
+            }
+            // Operadores binários associam à esquerda
+            Rule::concat_sift
+            | Rule::bit_or_sift
+            | Rule::bit_xor_sift
+            | Rule::bit_and_sift
+            | Rule::shift_sift
+            | Rule::additive_sift
+            | Rule::multiplicative_sift => {
+                let mut inner = pair.into_inner();
+                let mut sift = Sift::from_pair(inner.next().unwrap());
+
+                while let Some(op_pair) = inner.next() {
+                    let right = Sift::from_pair(inner.next().expect("Operador binário sem operando direito"));
+                    sift = Sift::Binary {
+                        left: Box::new(sift),
+                        op: BinaryOp::from_input(op_pair.as_str()),
+                        right: Box::new(right),
+                    };
+                }
+                sift
+            }
             Rule::comparison_sift => {
                 let mut inner = pair.into_inner();
                 let left = Sift::from_pair(inner.next().unwrap());
 
-                if let Some(op_pair) = inner.next() {
-                    if op_pair.as_rule() == Rule::comp_op {
+                match inner.next() {
+                    Some(op_pair) if op_pair.as_rule() == Rule::comp_op => {
                         let op = Op::from_input(op_pair.as_str());
-                        let right = Sift::from_pair(inner.next().unwrap());
+                        let right_pair = inner.next().unwrap();
+
+                        if right_pair.as_rule() == Rule::quantified {
+                            let mut quantified = right_pair.into_inner();
+                            let quantifier = Quantifier::from_input(quantified.next().unwrap().as_str());
+                            let sample = DQL::from_pair(quantified.next().unwrap());
+
+                            Sift::Quantified {
+                                left: Box::new(left),
+                                op,
+                                quantifier,
+                                sample: Box::new(sample),
+                            }
+                        } else {
+                            Sift::Comparison {
+                                left: Box::new(left),
+                                op,
+                                right: Box::new(Sift::from_pair(right_pair)),
+                            }
+                        }
+                    }
+                    Some(in_pair) if in_pair.as_rule() == Rule::in_sample => {
+                        let mut in_inner = in_pair.into_inner().peekable();
+                        let negated = in_inner.next_if(|p| p.as_rule() == Rule::negation).is_some();
+                        let sample = DQL::from_pair(in_inner.next().unwrap());
+
+                        Sift::InSample {
+                            sift: Box::new(left),
+                            sample: Box::new(sample),
+                            negated,
+                        }
+                    }
+                    Some(list_pair) if list_pair.as_rule() == Rule::in_list => {
+                        let mut list_inner = list_pair.into_inner().peekable();
+                        let negated = list_inner.next_if(|p| p.as_rule() == Rule::negation).is_some();
+
+                        Sift::InList {
+                            sift: Box::new(left),
+                            list: list_inner.map(Sift::from_pair).collect(),
+                            negated,
+                        }
+                    }
+                    Some(like_pair) if like_pair.as_rule() == Rule::like => {
+                        let mut like_inner = like_pair.into_inner().peekable();
+                        let negated = like_inner.next_if(|p| p.as_rule() == Rule::negation).is_some();
+                        let case_insensitive = like_inner.next().unwrap().as_str().eq_ignore_ascii_case("ILIKE");
+                        let pattern = Sift::from_pair(like_inner.next().expect("LIKE deve ter padrão"));
+                        let escape = like_inner.next().map(|p| Box::new(Sift::from_pair(p)));
 
-                        Sift::Comparison {
-                            left: Box::new(left),
-                            op,
-                            right: Box::new(right),
+                        Sift::Like {
+                            sift: Box::new(left),
+                            pattern: Box::new(pattern),
+                            escape,
+                            negated,
+                            case_insensitive,
                         }
-                    } else {
-                        left
                     }
-                } else {
-                    left
+                    Some(similar_pair) if similar_pair.as_rule() == Rule::similar => {
+                        let mut similar_inner = similar_pair.into_inner().peekable();
+                        let negated = similar_inner.next_if(|p| p.as_rule() == Rule::negation).is_some();
+                        let pattern = Sift::from_pair(similar_inner.next().expect("SIMILAR TO deve ter padrão"));
+                        let escape = similar_inner.next().map(|p| Box::new(Sift::from_pair(p)));
+
+                        Sift::SimilarTo {
+                            sift: Box::new(left),
+                            pattern: Box::new(pattern),
+                            escape,
+                            negated,
+                        }
+                    }
+                    Some(between_pair) if between_pair.as_rule() == Rule::between => {
+                        let mut between_inner = between_pair.into_inner().peekable();
+                        let negated = between_inner.next_if(|p| p.as_rule() == Rule::negation).is_some();
+                        let low = Sift::from_pair(between_inner.next().expect("BETWEEN deve ter limite inferior"));
+                        let high = Sift::from_pair(between_inner.next().expect("BETWEEN deve ter limite superior"));
+
+                        Sift::Between {
+                            sift: Box::new(left),
+                            low: Box::new(low),
+                            high: Box::new(high),
+                            negated,
+                        }
+                    }
+                    Some(is_null_pair) if is_null_pair.as_rule() == Rule::is_null => Sift::IsNull {
+                        sift: Box::new(left),
+                        negated: is_null_pair.into_inner().next().is_some(),
+                    },
+                    _ => left,
                 }
             }
             Rule::primary_sift => {
                 let inner_pair = pair.into_inner().next().unwrap();
                 Sift::from_pair(inner_pair)
             }
-            Rule::ident => {
-                Sift::Ident(pair.as_str().to_string())
+            Rule::sample_sift => {
+                let sample_pair = pair.into_inner().next().unwrap();
+                Sift::Sample(Box::new(DQL::from_pair(sample_pair)))
+            }
+            Rule::exists => {
+                let sample_pair = pair.into_inner().next().unwrap();
+                Sift::Exists(Box::new(DQL::from_pair(sample_pair)))
+            }
+            Rule::compound_ident => {
+                Sift::Ident(Ident::from_pair(pair))
+            }
+            Rule::excluded => {
+                Sift::Excluded(pair.into_inner().next().unwrap().as_str().to_string())
+            }
+            Rule::insert_value => {
+                Sift::InsertValue(pair.into_inner().next().unwrap().as_str().to_string())
+            }
+            Rule::cast_sift => {
+                let mut inner = pair.into_inner();
+                let mut sift = Sift::from_pair(inner.next().unwrap());
+
+                // x::a::b aplica da esquerda para a direita
+                for fauna_pair in inner {
+                    sift = Sift::Cast {
+                        sift: Box::new(sift),
+                        fauna: Fauna::from_pair(fauna_pair),
+                    };
+                }
+                sift
+            }
+            Rule::cast => {
+                let mut inner = pair.into_inner();
+                let sift = Sift::from_pair(inner.next().unwrap());
+                let fauna = Fauna::from_pair(inner.next().expect("CAST deve ter tipo"));
+
+                Sift::Cast {
+                    sift: Box::new(sift),
+                    fauna,
+                }
+            }
+            Rule::call => {
+                Sift::Call(Call::from_pair(pair))
+            }
+            Rule::case => {
+                Sift::Case(Case::from_pair(pair))
+            }
+            Rule::conditional => {
+                let inner_pair = pair.into_inner().next().unwrap();
+                let rule = inner_pair.as_rule();
+                let mut args: Vec<Sift> = inner_pair.into_inner().map(Sift::from_pair).collect();
+
+                match rule {
+                    Rule::coalesce => Sift::Coalesce(args),
+                    Rule::greatest => Sift::Greatest(args),
+                    Rule::least => Sift::Least(args),
+                    Rule::nullif => {
+                        let right = args.pop().unwrap();
+                        let left = args.pop().unwrap();
+                        Sift::NullIf(Box::new(left), Box::new(right))
+                    }
+                    _ => panic!("Unexpected rule in conditional: {:?}", rule),
+                }
+            }
+            Rule::windowed => {
+                let mut inner = pair.into_inner();
+                let sift = Sift::from_pair(inner.next().unwrap());
+                let over = Over::from_pair(inner.next().unwrap());
+
+                Sift::Windowed {
+                    sift: Box::new(sift),
+                    over,
+                }
+            }
+            Rule::window_call => {
+                Sift::WindowCall(WindowCall::from_pair(pair))
+            }
+            Rule::aggregate => {
+                Sift::Aggregate(Aggregate::from_pair(pair))
             }
             Rule::nutrient => {
                 Sift::Literal(Nutrients::from_pair(pair))
             }
+            Rule::default_value => Sift::Default,
             Rule::paren_sift => {
                 let sift_pair = pair.into_inner().next().unwrap();
                 Sift::Paren(Box::new(Sift::from_pair(sift_pair)))
@@ -86,4 +348,278 @@ impl Sift {
             _ => panic!("Unexpected rule in siftession: {:?}", pair.as_rule()),
         }
     }
+
+    pub fn from_input(input: String) -> Self {
+        let pair = SQLParser::parse_whole(Rule::sift, &input);
+        Sift::from_pair(pair)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sql::ast::dml::nutrients::Nutrients;
+    use crate::sql::ast::dql::ident::Ident;
+    use crate::sql::ast::dql::op::{BinaryOp, Op, Quantifier, UnaryOp};
+    use crate::sql::ast::dql::sift::Sift;
+    use crate::sql::ast::dql::DQL;
+
+    fn ident(name: &str) -> Box<Sift> {
+        Box::new(Sift::Ident(Ident::new(name)))
+    }
+
+    #[test]
+    fn in_and_not_in_subquery() {
+        match Sift::from_input("id IN (SELECT user_id FROM Orders)".to_string()) {
+            Sift::InSample { sift, sample, negated } => {
+                assert_eq!(sift, ident("id"));
+                assert!(matches!(*sample, DQL::Sample(ref sample) if sample.roots.len() == 1));
+                assert!(!negated);
+            }
+            other => panic!("Expected IN subquery, got {:?}", other),
+        }
+
+        assert!(matches!(
+            Sift::from_input("id NOT IN (SELECT user_id FROM Banned)".to_string()),
+            Sift::InSample { negated: true, .. }
+        ));
+    }
+
+    #[test]
+    fn exists_and_scalar_subqueries() {
+        match Sift::from_input("EXISTS (SELECT 1 FROM Orders WHERE paid = TRUE) AND total > (SELECT AVG(total) FROM Orders)".to_string()) {
+            Sift::And(left, right) => {
+                assert!(matches!(*left, Sift::Exists(_)));
+                match *right {
+                    Sift::Comparison { op: Op::Gt, right, .. } => assert!(matches!(*right, Sift::Sample(_))),
+                    other => panic!("Expected comparison, got {:?}", other),
+                }
+            }
+            other => panic!("Expected AND, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn quantified_comparisons() {
+        match Sift::from_input("price > ALL (SELECT price FROM Rival)".to_string()) {
+            Sift::Quantified { left, op, quantifier, .. } => {
+                assert_eq!(left, ident("price"));
+                assert_eq!(op, Op::Gt);
+                assert_eq!(quantifier, Quantifier::All);
+            }
+            other => panic!("Expected quantified comparison, got {:?}", other),
+        }
+
+        assert!(matches!(
+            Sift::from_input("price = SOME (SELECT price FROM Rival)".to_string()),
+            Sift::Quantified { quantifier: Quantifier::Any, .. }
+        ));
+    }
+
+    #[test]
+    fn subqueries_with_set_operations() {
+        match Sift::from_input("id IN (SELECT a FROM x UNION SELECT b FROM y)".to_string()) {
+            Sift::InSample { sample, negated: false, .. } => assert!(matches!(*sample, DQL::Bouquet(_))),
+            other => panic!("Expected IN subquery, got {:?}", other),
+        }
+
+        match Sift::from_input("EXISTS (SELECT a FROM x INTERSECT SELECT a FROM y)".to_string()) {
+            Sift::Exists(sample) => assert!(matches!(*sample, DQL::Bouquet(_))),
+            other => panic!("Expected EXISTS, got {:?}", other),
+        }
+
+        match Sift::from_input("price > ALL (SELECT price FROM a EXCEPT SELECT price FROM b)".to_string()) {
+            Sift::Quantified { sample, .. } => assert!(matches!(*sample, DQL::Bouquet(_))),
+            other => panic!("Expected quantified comparison, got {:?}", other),
+        }
+
+        match Sift::from_input("(SELECT MAX(a) FROM x UNION ALL SELECT MAX(a) FROM y ORDER BY 1 LIMIT 1)".to_string()) {
+            Sift::Sample(sample) => assert!(matches!(*sample, DQL::Bouquet(_))),
+            other => panic!("Expected scalar subquery, got {:?}", other),
+        }
+    }
+
+    fn int(value: i64) -> Box<Sift> {
+        Box::new(Sift::Literal(Nutrients::Int(value)))
+    }
+
+    fn binary(left: Box<Sift>, op: BinaryOp, right: Box<Sift>) -> Box<Sift> {
+        Box::new(Sift::Binary { left, op, right })
+    }
+
+    #[test]
+    fn arithmetic_precedence_and_associativity() {
+        // a + b * c - d  =>  (a + (b * c)) - d
+        assert_eq!(
+            Box::new(Sift::from_input("a + b * c - d".to_string())),
+            binary(binary(ident("a"), BinaryOp::Add, binary(ident("b"), BinaryOp::Mul, ident("c"))), BinaryOp::Sub, ident("d"))
+        );
+
+        // a / b % c  =>  (a / b) % c
+        assert_eq!(
+            Box::new(Sift::from_input("a / b % c".to_string())),
+            binary(binary(ident("a"), BinaryOp::Div, ident("b")), BinaryOp::Mod, ident("c"))
+        );
+
+        assert_eq!(
+            Box::new(Sift::from_input("(a + b) * 2".to_string())),
+            binary(Box::new(Sift::Paren(binary(ident("a"), BinaryOp::Add, ident("b")))), BinaryOp::Mul, int(2))
+        );
+    }
+
+    #[test]
+    fn bitwise_and_concat_precedence() {
+        // a | b & c << 1  =>  a | (b & (c << 1))
+        assert_eq!(
+            Box::new(Sift::from_input("a | b & c << 1".to_string())),
+            binary(ident("a"), BinaryOp::BitOr, binary(ident("b"), BinaryOp::BitAnd, binary(ident("c"), BinaryOp::ShiftLeft, int(1))))
+        );
+
+        // first || ' ' || last + 1  =>  (first || ' ') || (last + 1)
+        assert_eq!(
+            Box::new(Sift::from_input("first || ' ' || last + 1".to_string())),
+            binary(
+                binary(ident("first"), BinaryOp::Concat, Box::new(Sift::Literal(Nutrients::Str(" ".to_string())))),
+                BinaryOp::Concat,
+                binary(ident("last"), BinaryOp::Add, int(1)),
+            )
+        );
+
+        assert_eq!(
+            Box::new(Sift::from_input("a ^ b # c".to_string())),
+            binary(binary(ident("a"), BinaryOp::BitXor, ident("b")), BinaryOp::BitXor, ident("c"))
+        );
+    }
+
+    #[test]
+    fn unary_operators() {
+        assert_eq!(
+            Sift::from_input("-a * -1".to_string()),
+            Sift::Binary {
+                left: Box::new(Sift::Unary { op: UnaryOp::Neg, sift: ident("a") }),
+                op: BinaryOp::Mul,
+                right: Box::new(Sift::Unary { op: UnaryOp::Neg, sift: int(1) }),
+            }
+        );
+        assert_eq!(Sift::from_input("~flags".to_string()), Sift::Unary { op: UnaryOp::BitNot, sift: ident("flags") });
+    }
+
+    #[test]
+    fn not_binds_looser_than_comparison() {
+        // NOT a = 1 AND b  =>  (NOT (a = 1)) AND b
+        assert_eq!(
+            Sift::from_input("NOT a = 1 AND b".to_string()),
+            Sift::And(
+                Box::new(Sift::Unary {
+                    op: UnaryOp::Not,
+                    sift: Box::new(Sift::Comparison { left: ident("a"), op: Op::Eq, right: int(1) }),
+                }),
+                ident("b"),
+            )
+        );
+
+        assert!(matches!(
+            Sift::from_input("NOT EXISTS (SELECT 1 FROM Orders)".to_string()),
+            Sift::Unary { op: UnaryOp::Not, .. }
+        ));
+
+        // Comparação entre expressões aritméticas
+        assert_eq!(
+            Sift::from_input("price * qty >= total - 1".to_string()),
+            Sift::Comparison {
+                left: binary(ident("price"), BinaryOp::Mul, ident("qty")),
+                op: Op::Gte,
+                right: binary(ident("total"), BinaryOp::Sub, int(1)),
+            }
+        );
+    }
+
+    fn string(value: &str) -> Box<Sift> {
+        Box::new(Sift::Literal(Nutrients::Str(value.to_string())))
+    }
+
+    #[test]
+    fn like_and_ilike_with_escape() {
+        assert_eq!(
+            Sift::from_input("name LIKE 'a%' ESCAPE '!'".to_string()),
+            Sift::Like {
+                sift: ident("name"),
+                pattern: string("a%"),
+                escape: Some(string("!")),
+                negated: false,
+                case_insensitive: false,
+            }
+        );
+
+        assert_eq!(
+            Sift::from_input("name NOT ILIKE first || '%'".to_string()),
+            Sift::Like {
+                sift: ident("name"),
+                pattern: binary(ident("first"), BinaryOp::Concat, string("%")),
+                escape: None,
+                negated: true,
+                case_insensitive: true,
+            }
+        );
+
+        assert!(matches!(
+            Sift::from_input("code NOT SIMILAR TO '(a|b)%'".to_string()),
+            Sift::SimilarTo { negated: true, escape: None, .. }
+        ));
+    }
+
+    #[test]
+    fn between_binds_its_own_and() {
+        // age BETWEEN 18 AND 65 AND active  =>  (age BETWEEN 18 AND 65) AND active
+        assert_eq!(
+            Sift::from_input("age BETWEEN 18 AND 65 AND active".to_string()),
+            Sift::And(
+                Box::new(Sift::Between { sift: ident("age"), low: int(18), high: int(65), negated: false }),
+                ident("active"),
+            )
+        );
+
+        assert!(matches!(
+            Sift::from_input("total NOT BETWEEN low - 1 AND high + 1".to_string()),
+            Sift::Between { negated: true, .. }
+        ));
+    }
+
+    #[test]
+    fn in_list_and_not_in_list() {
+        assert_eq!(
+            Sift::from_input("status IN ('a', 'b')".to_string()),
+            Sift::InList { sift: ident("status"), list: vec![*string("a"), *string("b")], negated: false }
+        );
+
+        assert!(matches!(
+            Sift::from_input("id NOT IN (1, 2, 3)".to_string()),
+            Sift::InList { negated: true, ref list, .. } if list.len() == 3
+        ));
+
+        // Lista com um único elemento não é subconsulta
+        assert!(matches!(Sift::from_input("id IN (1)".to_string()), Sift::InList { .. }));
+    }
+
+    #[test]
+    fn conditional_functions() {
+        assert_eq!(
+            Sift::from_input("COALESCE(nickname, name, 'anon')".to_string()),
+            Sift::Coalesce(vec![*ident("nickname"), *ident("name"), *string("anon")])
+        );
+        assert_eq!(
+            Sift::from_input("NULLIF(total, 0)".to_string()),
+            Sift::NullIf(ident("total"), int(0))
+        );
+        assert_eq!(
+            Sift::from_input("greatest(a, b) - least(a, b)".to_string()),
+            Sift::Binary {
+                left: Box::new(Sift::Greatest(vec![*ident("a"), *ident("b")])),
+                op: BinaryOp::Sub,
+                right: Box::new(Sift::Least(vec![*ident("a"), *ident("b")])),
+            }
+        );
+
+        // Fora de chamada, continuam sendo nomes comuns
+        assert_eq!(Sift::from_input("least".to_string()), *ident("least"));
+    }
 }
@@ -1,32 +1,130 @@
 use pest::iterators::Pair;
 use serde::{Deserialize, Serialize};
-use crate::sql::parser::parser::Rule;
+use crate::sql::ast::dql::sift::Sift;
+use crate::sql::parser::parser::{Rule, SQLParser};
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Twigs {
     All,
-    Named(Vec<String>),
+    Named(Vec<Twig>),
+}
+
+// Item da projeção
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Twig {
+    Sift {
+        sift: Sift,
+        alias: Option<String>,
+    },
+    Wildcard(Option<String>), // * ou tabela.*
 }
 
 impl Twigs {
     pub fn from_pair(pair: Pair<Rule>) -> Self {
         assert_eq!(pair.as_rule(), Rule::twigs);
 
-        let content = pair.as_str();
+        let twigs: Vec<Twig> = pair.into_inner().map(Twig::from_pair).collect();
 
-        if content == "*" {
+        // Um "*" sozinho é a projeção completa
+        if twigs == [Twig::Wildcard(None)] {
             Twigs::All
         } else {
-            // É uma lista de colunas nomeadas
-            let mut columns = Vec::new();
+            Twigs::Named(twigs)
+        }
+    }
 
-            for inner_pair in pair.into_inner() {
-                if inner_pair.as_rule() == Rule::ident {
-                    columns.push(inner_pair.as_str().to_string());
+    pub fn from_input(input: String) -> Self {
+        let pair = SQLParser::parse_whole(Rule::twigs, &input);
+        Twigs::from_pair(pair)
+    }
+}
+
+impl Twig {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::twig);
+
+        let mut inner = pair.into_inner();
+        let first = inner.next().unwrap();
+
+        match first.as_rule() {
+            Rule::wildcard => {
+                let bud = first.into_inner().next().map(|p| p.as_str().to_string());
+                Twig::Wildcard(bud)
+            }
+            Rule::sift => {
+                let alias = inner
+                    .next()
+                    .map(|a| a.into_inner().next().unwrap().as_str().to_string());
+                Twig::Sift {
+                    sift: Sift::from_pair(first),
+                    alias,
                 }
             }
-
-            Twigs::Named(columns)
+            _ => panic!("Unexpected rule in projection: {:?}", first.as_rule()),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::sql::ast::dml::nutrients::Nutrients;
+    use crate::sql::ast::dql::call::Call;
+    use crate::sql::ast::dql::ident::Ident;
+    use crate::sql::ast::dql::op::BinaryOp;
+    use crate::sql::ast::dql::sift::Sift;
+    use crate::sql::ast::dql::twigs::{Twig, Twigs};
+
+    #[test]
+    fn lone_star_is_all() {
+        assert_eq!(Twigs::from_input("*".to_string()), Twigs::All);
+    }
+
+    #[test]
+    fn items_with_aliases_and_wildcards() {
+        let twigs = Twigs::from_input("price AS cost, title name, t.*, 1, *".to_string());
+
+        assert_eq!(twigs, Twigs::Named(vec![
+            Twig::Sift { sift: Sift::Ident(Ident::new("price")), alias: Some("cost".to_string()) },
+            Twig::Sift { sift: Sift::Ident(Ident::new("title")), alias: Some("name".to_string()) },
+            Twig::Wildcard(Some("t".to_string())),
+            Twig::Sift { sift: Sift::Literal(Nutrients::Int(1)), alias: None },
+            Twig::Wildcard(None),
+        ]));
+    }
+
+    #[test]
+    fn expressions_calls_and_qualified_wildcards() {
+        let twigs = Twigs::from_input("price * qty AS total, upper(name) n, t.*".to_string());
+
+        assert_eq!(twigs, Twigs::Named(vec![
+            Twig::Sift {
+                sift: Sift::Binary {
+                    left: Box::new(Sift::Ident(Ident::new("price"))),
+                    op: BinaryOp::Mul,
+                    right: Box::new(Sift::Ident(Ident::new("qty"))),
+                },
+                alias: Some("total".to_string()),
+            },
+            Twig::Sift {
+                sift: Sift::Call(Call {
+                    schema: None,
+                    name: "upper".to_string(),
+                    distinct: false,
+                    args: vec![Sift::Ident(Ident::new("name"))],
+                    ranks: vec![],
+                    filter: None,
+                }),
+                alias: Some("n".to_string()),
+            },
+            Twig::Wildcard(Some("t".to_string())),
+        ]));
+    }
+
+    #[test]
+    fn schema_qualified_wildcard() {
+        assert_eq!(Twigs::from_input("public.t.*, t.a".to_string()), Twigs::Named(vec![
+            Twig::Wildcard(Some("public.t".to_string())),
+            Twig::Sift { sift: Sift::Ident(Ident::from_input("t.a".to_string())), alias: None },
+        ]));
+    }
+}
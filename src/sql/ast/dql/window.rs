@@ -0,0 +1,332 @@
+use pest::iterators::Pair;
+use serde::{Deserialize, Serialize};
+use crate::sql::ast::dql::rank::Rank;
+use crate::sql::ast::dql::sift::Sift;
+use crate::sql::parser::parser::Rule;
+
+// Chamada de função de janela, sempre acompanhada de OVER
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WindowCall {
+    pub func: WindowFn,
+    pub args: Vec<Sift>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WindowFn {
+    RowNumber,
+    Rank,
+    DenseRank,
+    PercentRank,
+    CumeDist,
+    Ntile,
+    Lag,
+    Lead,
+    FirstValue,
+    LastValue,
+    NthValue,
+}
+
+// OVER w | OVER (...)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Over {
+    Named(String),
+    Window(Window),
+}
+
+// Especificação de janela
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Window {
+    pub base: Option<String>,
+    pub partition: Vec<Sift>,
+    pub ranks: Vec<Rank>,
+    pub frame: Option<Frame>,
+}
+
+// WINDOW nome AS (...)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NamedWindow {
+    pub bud: String,
+    pub window: Window,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Frame {
+    pub unit: FrameUnit,
+    pub start: FrameBound,
+    pub end: Option<FrameBound>, // presente só na forma BETWEEN
+    pub exclusion: Option<FrameExclusion>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FrameUnit {
+    Rows,
+    Range,
+    Groups,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FrameBound {
+    UnboundedPreceding,
+    Preceding(Box<Sift>),
+    CurrentRow,
+    Following(Box<Sift>),
+    UnboundedFollowing,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FrameExclusion {
+    CurrentRow,
+    Group,
+    Ties,
+    NoOthers,
+}
+
+impl WindowCall {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::window_call);
+
+        let mut inner = pair.into_inner();
+        let func = WindowFn::from_pair(inner.next().expect("Função de janela deve ter nome"));
+        let args = inner.map(Sift::from_pair).collect();
+
+        Self { func, args }
+    }
+}
+
+impl WindowFn {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::window_fn);
+
+        match pair.as_str().to_uppercase().as_str() {
+            "ROW_NUMBER" => WindowFn::RowNumber,
+            "RANK" => WindowFn::Rank,
+            "DENSE_RANK" => WindowFn::DenseRank,
+            "PERCENT_RANK" => WindowFn::PercentRank,
+            "CUME_DIST" => WindowFn::CumeDist,
+            "NTILE" => WindowFn::Ntile,
+            "LAG" => WindowFn::Lag,
+            "LEAD" => WindowFn::Lead,
+            "FIRST_VALUE" => WindowFn::FirstValue,
+            "LAST_VALUE" => WindowFn::LastValue,
+            "NTH_VALUE" => WindowFn::NthValue,
+            _ => panic!("Unknown window function: {}", pair.as_str()),
+        }
+    }
+}
+
+impl Over {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::over);
+
+        let inner_pair = pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::ident => Over::Named(inner_pair.as_str().to_string()),
+            Rule::window => Over::Window(Window::from_pair(inner_pair)),
+            _ => panic!("Unexpected rule in OVER: {:?}", inner_pair.as_rule()),
+        }
+    }
+}
+
+impl Window {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::window);
+
+        let mut window = Window { base: None, partition: Vec::new(), ranks: Vec::new(), frame: None };
+
+        for inner_pair in pair.into_inner() {
+            match inner_pair.as_rule() {
+                Rule::ident => window.base = Some(inner_pair.as_str().to_string()),
+                Rule::partition_by => window.partition = inner_pair.into_inner().map(Sift::from_pair).collect(),
+                Rule::order_by => window.ranks = Rank::from_order_by(inner_pair),
+                Rule::frame => window.frame = Some(Frame::from_pair(inner_pair)),
+                _ => panic!("Unexpected rule in window: {:?}", inner_pair.as_rule()),
+            }
+        }
+
+        window
+    }
+}
+
+impl NamedWindow {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::named_window);
+
+        let mut inner = pair.into_inner();
+        let bud = inner.next().unwrap().as_str().to_string();
+        let window = Window::from_pair(inner.next().unwrap());
+
+        Self { bud, window }
+    }
+
+    // Lista da cláusula WINDOW
+    pub fn from_window_clause(pair: Pair<Rule>) -> Vec<Self> {
+        assert_eq!(pair.as_rule(), Rule::window_clause);
+
+        pair.into_inner().map(NamedWindow::from_pair).collect()
+    }
+}
+
+impl Frame {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::frame);
+
+        let mut inner = pair.into_inner();
+
+        let unit = match inner.next().unwrap().as_str().to_uppercase().as_str() {
+            "ROWS" => FrameUnit::Rows,
+            "RANGE" => FrameUnit::Range,
+            "GROUPS" => FrameUnit::Groups,
+            other => panic!("Unknown frame unit: {}", other),
+        };
+
+        let start = FrameBound::from_pair(inner.next().expect("Frame deve ter início"));
+        let mut end = None;
+        let mut exclusion = None;
+
+        for inner_pair in inner {
+            match inner_pair.as_rule() {
+                Rule::frame_bound => end = Some(FrameBound::from_pair(inner_pair)),
+                Rule::frame_exclusion => exclusion = Some(FrameExclusion::from_pair(inner_pair)),
+                _ => panic!("Unexpected rule in frame: {:?}", inner_pair.as_rule()),
+            }
+        }
+
+        Self { unit, start, end, exclusion }
+    }
+}
+
+impl FrameBound {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::frame_bound);
+
+        let upper = pair.as_str().to_uppercase();
+        let following = upper.ends_with("FOLLOWING");
+
+        match pair.into_inner().next() {
+            Some(sift_pair) => {
+                let sift = Box::new(Sift::from_pair(sift_pair));
+                if following { FrameBound::Following(sift) } else { FrameBound::Preceding(sift) }
+            }
+            None if upper.starts_with("CURRENT") => FrameBound::CurrentRow,
+            None if following => FrameBound::UnboundedFollowing,
+            None => FrameBound::UnboundedPreceding,
+        }
+    }
+}
+
+impl FrameExclusion {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::frame_exclusion);
+
+        let upper = pair.as_str().to_uppercase();
+        match upper.split_whitespace().nth(1).unwrap() {
+            "CURRENT" => FrameExclusion::CurrentRow,
+            "GROUP" => FrameExclusion::Group,
+            "TIES" => FrameExclusion::Ties,
+            "NO" => FrameExclusion::NoOthers,
+            other => panic!("Unknown frame exclusion: {}", other),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sql::ast::dml::nutrients::Nutrients;
+    use crate::sql::ast::dql::aggregate::{Aggregate, AggregateFn};
+    use crate::sql::ast::dql::ident::Ident;
+    use crate::sql::ast::dql::rank::{Direction, Rank};
+    use crate::sql::ast::dql::sample::Sample;
+    use crate::sql::ast::dql::sift::Sift;
+    use crate::sql::ast::dql::window::{Frame, FrameBound, FrameExclusion, FrameUnit, Over, Window, WindowCall, WindowFn};
+
+    fn ident(name: &str) -> Sift {
+        Sift::Ident(Ident::new(name))
+    }
+
+    #[test]
+    fn ranking_function_over_partition() {
+        let sift = Sift::from_input("ROW_NUMBER() OVER (PARTITION BY dept ORDER BY salary DESC)".to_string());
+
+        assert_eq!(sift, Sift::Windowed {
+            sift: Box::new(Sift::WindowCall(WindowCall { func: WindowFn::RowNumber, args: vec![] })),
+            over: Over::Window(Window {
+                base: None,
+                partition: vec![ident("dept")],
+                ranks: vec![Rank { sift: ident("salary"), direction: Some(Direction::Desc), nulls: None }],
+                frame: None,
+            }),
+        });
+    }
+
+    #[test]
+    fn value_function_with_arguments() {
+        let sift = Sift::from_input("LAG(price, 1) OVER (ORDER BY day)".to_string());
+
+        match sift {
+            Sift::Windowed { sift, .. } => assert_eq!(*sift, Sift::WindowCall(WindowCall {
+                func: WindowFn::Lag,
+                args: vec![ident("price"), Sift::Literal(Nutrients::Int(1))],
+            })),
+            other => panic!("Expected windowed call, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn aggregate_with_frame() {
+        let input = "SUM(total) OVER (ORDER BY day ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW EXCLUDE TIES)".to_string();
+
+        match Sift::from_input(input) {
+            Sift::Windowed { sift, over: Over::Window(window) } => {
+                assert_eq!(*sift, Sift::Aggregate(Aggregate {
+                    func: AggregateFn::Sum,
+                    distinct: false,
+                    args: vec![ident("total")],
+                    ranks: vec![],
+                    filter: None,
+                }));
+                assert_eq!(window.frame, Some(Frame {
+                    unit: FrameUnit::Rows,
+                    start: FrameBound::UnboundedPreceding,
+                    end: Some(FrameBound::CurrentRow),
+                    exclusion: Some(FrameExclusion::Ties),
+                }));
+            }
+            other => panic!("Expected windowed aggregate, got {:?}", other),
+        }
+
+        let sift = Sift::from_input("AVG(x) OVER (RANGE 3 PRECEDING)".to_string());
+        match sift {
+            Sift::Windowed { over: Over::Window(window), .. } => assert_eq!(window.frame, Some(Frame {
+                unit: FrameUnit::Range,
+                start: FrameBound::Preceding(Box::new(Sift::Literal(Nutrients::Int(3)))),
+                end: None,
+                exclusion: None,
+            })),
+            other => panic!("Expected windowed aggregate, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn named_windows() {
+        let input = "SELECT SUM(total) OVER w, RANK() OVER (w2 ORDER BY total) FROM Orders \
+            WINDOW w AS (PARTITION BY customer), w2 AS (w) ORDER BY customer".to_string();
+        let sample = Sample::from_input(input);
+
+        assert_eq!(sample.windows.len(), 2);
+        assert_eq!(sample.windows[0].bud, "w");
+        assert_eq!(sample.windows[0].window.partition, vec![ident("customer")]);
+        assert_eq!(sample.windows[1].window.base, Some("w".to_string()));
+        assert_eq!(sample.ranks.len(), 1);
+    }
+
+    #[test]
+    fn window_words_are_not_reserved() {
+        let input = "SELECT partition, SUM(over) OVER window FROM window WINDOW window AS (partition PARTITION BY over)".to_string();
+        let sample = Sample::from_input(input);
+
+        assert_eq!(sample.piths.to_string(), "partition, SUM(over) OVER window");
+        assert_eq!(sample.windows[0].bud, "window");
+        assert_eq!(sample.windows[0].window.base, Some("partition".to_string()));
+        assert_eq!(sample.windows[0].window.partition, vec![ident("over")]);
+    }
+}
@@ -0,0 +1,129 @@
+use pest::iterators::Pair;
+use serde::{Deserialize, Serialize};
+use crate::sql::ast::sql::Sql;
+use crate::sql::parser::parser::{Rule, SQLParser};
+
+// EXPLAIN [ANALYZE] [VERBOSE] comando | EXPLAIN (opção [valor], ...) comando
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Explain {
+    pub analyze: bool,
+    pub verbose: bool,
+    pub options: Vec<ExplainOption>, // só na forma entre parênteses
+    pub sql: Box<Sql>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExplainOption {
+    pub name: String,
+    pub value: Option<String>,
+}
+
+impl Explain {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::explain);
+
+        let mut analyze = false;
+        let mut verbose = false;
+        let mut options = Vec::new();
+        let mut sql = None;
+
+        for inner_pair in pair.into_inner() {
+            match inner_pair.as_rule() {
+                Rule::analyze => analyze = true,
+                Rule::verbose => verbose = true,
+                Rule::explain_options => {
+                    options = inner_pair.into_inner().map(ExplainOption::from_pair).collect();
+                }
+                Rule::explained => {
+                    let statement = inner_pair.into_inner().next().unwrap();
+                    sql = Sql::from_statement(statement);
+                }
+                _ => panic!("Unexpected rule in EXPLAIN: {:?}", inner_pair.as_rule()),
+            }
+        }
+
+        // Na forma entre parênteses, ANALYZE e VERBOSE também são opções
+        analyze |= options.iter().any(|o| o.is_enabled("ANALYZE"));
+        verbose |= options.iter().any(|o| o.is_enabled("VERBOSE"));
+
+        Self {
+            analyze,
+            verbose,
+            options,
+            sql: Box::new(sql.expect("EXPLAIN deve ter um comando")),
+        }
+    }
+
+    pub fn from_input(input: String) -> Self {
+        let pair = SQLParser::parse_whole(Rule::explain, &input);
+        Explain::from_pair(pair)
+    }
+}
+
+impl ExplainOption {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::explain_option);
+
+        let mut inner = pair.into_inner();
+        let name = inner.next().expect("Opção deve ter nome").as_str().to_string();
+        let value = inner.next().map(|p| p.as_str().to_string());
+
+        Self { name, value }
+    }
+
+    // Opção booleana ligada: ausência de valor equivale a TRUE
+    pub fn is_enabled(&self, name: &str) -> bool {
+        self.name.eq_ignore_ascii_case(name)
+            && self.value.as_deref().is_none_or(|v| ["TRUE", "ON", "1"].iter().any(|t| v.eq_ignore_ascii_case(t)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sql::ast::dql::DQL;
+    use crate::sql::ast::explain::{Explain, ExplainOption};
+    use crate::sql::ast::sql::Sql;
+    use crate::sql::ast::tcl::{BeginKeyword, TCL};
+    use crate::sql::parser::tree::Tree;
+
+    #[test]
+    fn bare_and_analyze_forms() {
+        let explain = Explain::from_input("EXPLAIN SELECT * FROM Product".to_string());
+        assert!(!explain.analyze);
+        assert!(!explain.verbose);
+        assert!(matches!(*explain.sql, Sql::DQL(DQL::Sample(_))));
+
+        let explain = Explain::from_input("explain analyze verbose DELETE FROM Product".to_string());
+        assert!(explain.analyze);
+        assert!(explain.verbose);
+        assert!(explain.options.is_empty());
+        assert!(matches!(*explain.sql, Sql::DML(_)));
+    }
+
+    #[test]
+    fn option_list() {
+        let explain = Explain::from_input("EXPLAIN (ANALYZE, FORMAT JSON, COSTS OFF) SELECT 1".to_string());
+
+        assert!(explain.analyze);
+        assert_eq!(explain.options, vec![
+            ExplainOption { name: "ANALYZE".to_string(), value: None },
+            ExplainOption { name: "FORMAT".to_string(), value: Some("JSON".to_string()) },
+            ExplainOption { name: "COSTS".to_string(), value: Some("OFF".to_string()) },
+        ]);
+
+        let explain = Explain::from_input("EXPLAIN (ANALYZE false) SELECT 1".to_string());
+        assert!(!explain.analyze);
+    }
+
+    #[test]
+    fn explain_is_a_statement_in_the_tree() {
+        let tree = Tree::parse("EXPLAIN (ANALYZE) SELECT 1; BEGIN;".to_string());
+
+        assert_eq!(tree.sqls.len(), 2);
+        match &tree.sqls[0] {
+            Sql::Explain(explain) => assert!(matches!(*explain.sql, Sql::DQL(_))),
+            other => panic!("Expected EXPLAIN, got {:?}", other),
+        }
+        assert_eq!(tree.sqls[1], Sql::TCL(TCL::Begin { keyword: BeginKeyword::Begin, modes: vec![] }));
+    }
+}
@@ -1,5 +1,8 @@
 pub mod comment;
+pub mod dcl;
 pub mod ddl;
 pub mod dml;
 pub mod dql;
+pub mod explain;
+pub mod tcl;
 pub mod sql;
@@ -2,13 +2,19 @@
 use pest::iterators::Pair;
 use serde::{Deserialize, Serialize};
 use crate::sql::{ast::{comment::Comment, ddl::DDL, dql::DQL}, parser::parser::Rule};
+use crate::sql::ast::dcl::DCL;
 use crate::sql::ast::dml::DML;
+use crate::sql::ast::explain::Explain;
+use crate::sql::ast::tcl::TCL;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Sql {
     DDL(DDL),
     DML(DML),
     DQL(DQL),
+    TCL(TCL),
+    DCL(DCL),
+    Explain(Explain),
     Comment(Comment),
 }
 
@@ -17,13 +23,21 @@ impl Sql {
         assert_eq!(pair.as_rule(), Rule::sql);
 
         pair.into_inner()
-            .filter_map(|p| match p.as_rule() {
-                Rule::DDL => Some(Sql::DDL(DDL::from_pair(p))),
-                Rule::DML => Some(Sql::DML(DML::from_pair(p))),
-                Rule::DQL => Some(Sql::DQL(DQL::from_pair(p))),
-                Rule::comment => Some(Sql::Comment(Comment::from_pair(p))),
-                _ => None,
-            })
+            .filter_map(Sql::from_statement)
             .collect()
     }
+
+    // Um único comando; também usado pelo EXPLAIN
+    pub fn from_statement(pair: Pair<Rule>) -> Option<Self> {
+        match pair.as_rule() {
+            Rule::DDL => Some(Sql::DDL(DDL::from_pair(pair))),
+            Rule::DML => Some(Sql::DML(DML::from_pair(pair))),
+            Rule::DQL => Some(Sql::DQL(DQL::from_pair(pair))),
+            Rule::TCL => Some(Sql::TCL(TCL::from_pair(pair))),
+            Rule::DCL => Some(Sql::DCL(DCL::from_pair(pair))),
+            Rule::explain => Some(Sql::Explain(Explain::from_pair(pair))),
+            Rule::comment => Some(Sql::Comment(Comment::from_pair(pair))),
+            _ => None,
+        }
+    }
 }
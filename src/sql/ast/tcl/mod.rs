@@ -0,0 +1,185 @@
+pub mod mode;
+
+use pest::iterators::Pair;
+use serde::{Deserialize, Serialize};
+use crate::sql::ast::tcl::mode::TransactionMode;
+use crate::sql::parser::parser::{Rule, SQLParser};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TCL {
+    Begin {
+        keyword: BeginKeyword,
+        modes: Vec<TransactionMode>,
+    },
+    Commit {
+        chain: Option<bool>, // AND [NO] CHAIN
+    },
+    Rollback {
+        savepoint: Option<String>, // ROLLBACK TO s1
+        chain: Option<bool>,
+    },
+    Savepoint(String),
+    Release(String),
+    SetTransaction(Vec<TransactionMode>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BeginKeyword {
+    Begin, // BEGIN [WORK | TRANSACTION]
+    Start, // START TRANSACTION
+}
+
+impl TCL {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::TCL);
+
+        let inner_pair = pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::begin => {
+                let keyword = match inner_pair.as_str().to_uppercase().starts_with("START") {
+                    true => BeginKeyword::Start,
+                    false => BeginKeyword::Begin,
+                };
+                TCL::Begin { keyword, modes: Self::modes(inner_pair) }
+            }
+            Rule::set_transaction => TCL::SetTransaction(Self::modes(inner_pair)),
+            Rule::commit => TCL::Commit { chain: Self::chain(inner_pair) },
+            Rule::rollback => {
+                let savepoint = inner_pair
+                    .clone()
+                    .into_inner()
+                    .find(|p| p.as_rule() == Rule::ident)
+                    .map(|p| p.as_str().to_string());
+
+                TCL::Rollback { savepoint, chain: Self::chain(inner_pair) }
+            }
+            Rule::savepoint => TCL::Savepoint(Self::name(inner_pair)),
+            Rule::release => TCL::Release(Self::name(inner_pair)),
+            _ => panic!("Unexpected rule: {:?}", inner_pair.as_rule()),
+        }
+    }
+
+    pub fn from_input(input: String) -> Self {
+        let pair = SQLParser::parse_whole(Rule::TCL, &input);
+        TCL::from_pair(pair)
+    }
+
+    fn modes(pair: Pair<Rule>) -> Vec<TransactionMode> {
+        pair.into_inner().map(TransactionMode::from_pair).collect()
+    }
+
+    // None quando não escrito; AND NO CHAIN é Some(false), que também é o padrão
+    fn chain(pair: Pair<Rule>) -> Option<bool> {
+        pair.into_inner()
+            .find(|p| p.as_rule() == Rule::chain)
+            .map(|p| !p.as_str().to_uppercase().split_whitespace().any(|w| w == "NO"))
+    }
+
+    fn name(pair: Pair<Rule>) -> String {
+        pair.into_inner()
+            .next()
+            .expect("SAVEPOINT deve ter nome")
+            .as_str()
+            .to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sql::ast::sql::Sql;
+    use crate::sql::ast::tcl::mode::{IsolationLevel, TransactionMode};
+    use crate::sql::ast::tcl::{BeginKeyword, TCL};
+    use crate::sql::parser::tree::Tree;
+
+    #[test]
+    fn begin_and_commit_forms() {
+        assert_eq!(TCL::from_input("BEGIN".to_string()), TCL::Begin { keyword: BeginKeyword::Begin, modes: vec![] });
+        assert_eq!(TCL::from_input("begin work".to_string()), TCL::Begin { keyword: BeginKeyword::Begin, modes: vec![] });
+        assert_eq!(
+            TCL::from_input("START TRANSACTION ISOLATION LEVEL REPEATABLE READ, READ ONLY".to_string()),
+            TCL::Begin {
+                keyword: BeginKeyword::Start,
+                modes: vec![TransactionMode::Isolation(IsolationLevel::RepeatableRead), TransactionMode::ReadOnly],
+            }
+        );
+
+        assert_eq!(TCL::from_input("COMMIT".to_string()), TCL::Commit { chain: None });
+        assert_eq!(TCL::from_input("END TRANSACTION".to_string()), TCL::Commit { chain: None });
+        assert_eq!(TCL::from_input("COMMIT AND CHAIN".to_string()), TCL::Commit { chain: Some(true) });
+        assert_eq!(TCL::from_input("COMMIT AND NO CHAIN".to_string()), TCL::Commit { chain: Some(false) });
+    }
+
+    #[test]
+    fn spelling_survives_printing() {
+        for input in ["START TRANSACTION READ ONLY", "BEGIN", "COMMIT AND NO CHAIN", "ROLLBACK AND CHAIN"] {
+            assert_eq!(TCL::from_input(input.to_string()).to_string(), input);
+        }
+    }
+
+    #[test]
+    fn savepoints() {
+        assert_eq!(TCL::from_input("SAVEPOINT s1".to_string()), TCL::Savepoint("s1".to_string()));
+        assert_eq!(TCL::from_input("RELEASE s1".to_string()), TCL::Release("s1".to_string()));
+        assert_eq!(TCL::from_input("RELEASE SAVEPOINT s1".to_string()), TCL::Release("s1".to_string()));
+        assert_eq!(
+            TCL::from_input("ROLLBACK TO SAVEPOINT s1".to_string()),
+            TCL::Rollback { savepoint: Some("s1".to_string()), chain: None }
+        );
+        assert_eq!(
+            TCL::from_input("ROLLBACK".to_string()),
+            TCL::Rollback { savepoint: None, chain: None }
+        );
+    }
+
+    #[test]
+    fn set_transaction() {
+        assert_eq!(
+            TCL::from_input("SET TRANSACTION ISOLATION LEVEL SERIALIZABLE".to_string()),
+            TCL::SetTransaction(vec![TransactionMode::Isolation(IsolationLevel::Serializable)])
+        );
+        assert_eq!(
+            TCL::from_input("SET TRANSACTION READ WRITE NOT DEFERRABLE".to_string()),
+            TCL::SetTransaction(vec![TransactionMode::ReadWrite, TransactionMode::NotDeferrable])
+        );
+    }
+
+    #[test]
+    fn migration_wrapped_in_transaction() {
+        let tree = Tree::parse(
+            "BEGIN;\nUPDATE Product SET price = 1;\nSAVEPOINT s1;\nDELETE FROM Product;\nROLLBACK TO s1;\nCOMMIT;".to_string(),
+        );
+
+        assert_eq!(tree.sqls.len(), 6);
+        assert_eq!(tree.sqls[0], Sql::TCL(TCL::Begin { keyword: BeginKeyword::Begin, modes: vec![] }));
+        assert_eq!(tree.sqls[4], Sql::TCL(TCL::Rollback { savepoint: Some("s1".to_string()), chain: None }));
+        assert_eq!(tree.sqls[5], Sql::TCL(TCL::Commit { chain: None }));
+    }
+
+    #[test]
+    fn statement_words_are_not_reserved() {
+        let tree = Tree::parse(
+            "SELECT begin, commit, release, rollback, savepoint, explain, drop, grant, revoke FROM release;\n\
+             COMMIT;\n\
+             SELECT a FROM t AS begin;\n\
+             ROLLBACK TO savepoint;\n\
+             RELEASE SAVEPOINT savepoint".to_string(),
+        );
+
+        assert_eq!(tree.sqls.len(), 5);
+        assert!(matches!(tree.sqls[0], Sql::DQL(_)));
+        assert_eq!(tree.sqls[1], Sql::TCL(TCL::Commit { chain: None }));
+        assert_eq!(tree.sqls[3], Sql::TCL(TCL::Rollback { savepoint: Some("savepoint".to_string()), chain: None }));
+        assert_eq!(tree.sqls[4], Sql::TCL(TCL::Release("savepoint".to_string())));
+    }
+
+    #[test]
+    fn statements_need_a_semicolon_between_them() {
+        assert!(Tree::try_parse("SELECT a FROM t BEGIN".to_string()).is_err());
+        assert!(Tree::try_parse("SELECT a FROM t COMMIT".to_string()).is_err());
+        assert!(Tree::try_parse("UPDATE t SET a = 1 DELETE FROM t".to_string()).is_err());
+
+        // O último pode vir sem, mesmo com comentário depois
+        let tree = Tree::parse("SELECT a FROM t; BEGIN -- fim\n".to_string());
+        assert_eq!(tree.sqls.len(), 3);
+    }
+}
@@ -0,0 +1,48 @@
+use pest::iterators::Pair;
+use serde::{Deserialize, Serialize};
+use crate::sql::parser::parser::Rule;
+
+// Opções de BEGIN / SET TRANSACTION
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TransactionMode {
+    Isolation(IsolationLevel),
+    ReadOnly,
+    ReadWrite,
+    Deferrable,
+    NotDeferrable,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IsolationLevel {
+    ReadUncommitted,
+    ReadCommitted,
+    RepeatableRead,
+    Serializable,
+}
+
+impl TransactionMode {
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::transaction_mode);
+
+        let inner_pair = pair.into_inner().next().unwrap();
+
+        // Palavras-chave são silenciosas; o texto decide a variante
+        let words = inner_pair.as_str().to_uppercase();
+        let words: Vec<&str> = words.split_whitespace().collect();
+
+        match inner_pair.as_rule() {
+            Rule::isolation_level => TransactionMode::Isolation(match words[2..] {
+                ["SERIALIZABLE"] => IsolationLevel::Serializable,
+                ["REPEATABLE", "READ"] => IsolationLevel::RepeatableRead,
+                ["READ", "COMMITTED"] => IsolationLevel::ReadCommitted,
+                ["READ", "UNCOMMITTED"] => IsolationLevel::ReadUncommitted,
+                _ => panic!("Unknown isolation level: {}", inner_pair.as_str()),
+            }),
+            Rule::access_mode if words[1] == "ONLY" => TransactionMode::ReadOnly,
+            Rule::access_mode => TransactionMode::ReadWrite,
+            Rule::deferrable if words[0] == "NOT" => TransactionMode::NotDeferrable,
+            Rule::deferrable => TransactionMode::Deferrable,
+            _ => panic!("Unexpected rule in transaction mode: {:?}", inner_pair.as_rule()),
+        }
+    }
+}
@@ -0,0 +1,593 @@
+use std::fmt;
+use pest::iterators::Pair;
+use crate::sql::ast::sql::Sql;
+use crate::sql::parser::error::ParseError;
+use crate::sql::parser::parser::Rule;
+use crate::sql::parser::tree::Tree;
+use crate::sql::printer::formatter::Formatter;
+
+// Árvore concreta sem perdas: todo byte da entrada está em exatamente um Token,
+// inclusive espaços, comentários e a grafia original das palavras-chave
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cst {
+    source: String,
+    root: Node,
+    tree: Tree,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Node {
+    pub rule: Rule,
+    pub span: Span,
+    pub children: Vec<Element>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Element {
+    Node(Node),
+    Token(Token),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub span: Span,
+    pub text: String,
+}
+
+// Keyword e Punct são sempre texto literal da gramática (^"SELECT", "(");
+// palavras livres vêm de regras nomeadas: ident e as palavras de opção do EXPLAIN
+// são Ident, nomes de tipo são Type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenKind {
+    Keyword,
+    Ident,
+    Type,
+    Literal,
+    Punct,
+    Whitespace,
+    Comment,
+}
+
+impl Span {
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+}
+
+impl TokenKind {
+    // Trivia não tem significado para o parser
+    pub fn is_trivia(&self) -> bool {
+        matches!(self, TokenKind::Whitespace | TokenKind::Comment)
+    }
+
+    // Regras atômicas que viram um único token
+    fn from_pair(pair: &Pair<Rule>) -> Option<Self> {
+        match pair.as_rule() {
+            Rule::ident | Rule::call_name => Some(TokenKind::Ident),
+            Rule::fauna => Some(TokenKind::Type),
+            Rule::string | Rule::number | Rule::kw_true | Rule::kw_false | Rule::kw_null => Some(TokenKind::Literal),
+            Rule::line_comment | Rule::block_comment => Some(TokenKind::Comment),
+            // FORMAT JSON, ANALYZE TRUE: nomes e valores livres, como no PostgreSQL
+            Rule::explain_word => match pair.as_str().starts_with(|c: char| c == '\'' || c.is_ascii_digit()) {
+                true => Some(TokenKind::Literal),
+                false => Some(TokenKind::Ident),
+            },
+            _ => None,
+        }
+    }
+}
+
+impl Token {
+    pub fn is_trivia(&self) -> bool {
+        self.kind.is_trivia()
+    }
+}
+
+impl Node {
+    // O texto entre os filhos (palavras-chave, pontuação e espaços) vira tokens
+    fn from_pair(pair: Pair<Rule>, source: &str) -> Self {
+        let span = Span { start: pair.as_span().start(), end: pair.as_span().end() };
+        let rule = pair.as_rule();
+        let mut children = Vec::new();
+        let mut cursor = span.start;
+
+        for inner_pair in pair.into_inner().filter(|p| p.as_rule() != Rule::EOI) {
+            let start = inner_pair.as_span().start();
+            children.extend(lex(source, cursor, start).into_iter().map(Element::Token));
+            cursor = inner_pair.as_span().end();
+
+            // Espaço no fim de um filho fica entre ele e o irmão seguinte
+            let mut child = Element::from_pair(inner_pair, source);
+            let trailing = match &mut child {
+                Element::Node(node) => node.split_trailing_trivia(),
+                Element::Token(_) => Vec::new(),
+            };
+            children.push(child);
+            children.extend(trailing.into_iter().map(Element::Token));
+        }
+        children.extend(lex(source, cursor, span.end).into_iter().map(Element::Token));
+
+        Self { rule, span, children }
+    }
+
+    fn split_trailing_trivia(&mut self) -> Vec<Token> {
+        let mut trailing = Vec::new();
+        while let Some(Element::Token(token)) = self.children.last() {
+            if token.kind != TokenKind::Whitespace {
+                break;
+            }
+            self.span.end = token.span.start;
+            if let Some(Element::Token(token)) = self.children.pop() {
+                trailing.insert(0, token);
+            }
+        }
+        trailing
+    }
+
+    // Folhas em ordem, incluindo trivia
+    pub fn tokens(&self) -> Vec<&Token> {
+        let mut tokens = Vec::new();
+        for child in &self.children {
+            match child {
+                Element::Node(node) => tokens.extend(node.tokens()),
+                Element::Token(token) => tokens.push(token),
+            }
+        }
+        tokens
+    }
+
+    // Descendentes (e o próprio nó) com a regra pedida, em ordem de origem
+    pub fn find(&self, rule: Rule) -> Vec<&Node> {
+        let mut nodes = Vec::new();
+        if self.rule == rule {
+            nodes.push(self);
+        }
+        for child in &self.children {
+            if let Element::Node(node) = child {
+                nodes.extend(node.find(rule));
+            }
+        }
+        nodes
+    }
+
+    pub fn text(&self) -> String {
+        self.tokens().iter().map(|token| token.text.as_str()).collect()
+    }
+}
+
+impl Element {
+    fn from_pair(pair: Pair<Rule>, source: &str) -> Self {
+        let leaf = pair.clone().into_inner().next().is_none();
+
+        match TokenKind::from_pair(&pair) {
+            Some(kind) if leaf => Element::Token(Token {
+                kind,
+                span: Span { start: pair.as_span().start(), end: pair.as_span().end() },
+                text: pair.as_str().to_string(),
+            }),
+            _ => Element::Node(Node::from_pair(pair, source)),
+        }
+    }
+
+    pub fn span(&self) -> Span {
+        match self {
+            Element::Node(node) => node.span,
+            Element::Token(token) => token.span,
+        }
+    }
+}
+
+// Quebra o texto que a gramática não nomeia em tokens. Identificadores, literais e
+// comentários sempre vêm em pares próprios, então aqui só sobram espaço e o texto
+// literal das regras: palavras-chave e pontuação
+fn lex(source: &str, start: usize, end: usize) -> Vec<Token> {
+    const PUNCTS: [&str; 8] = ["::", "<>", "!=", "<=", ">=", "||", "<<", ">>"];
+
+    let mut tokens = Vec::new();
+    let mut cursor = start;
+
+    while cursor < end {
+        let rest = &source[cursor..end];
+        let first = rest.chars().next().unwrap();
+
+        let (kind, len) = if first.is_whitespace() {
+            (TokenKind::Whitespace, rest.find(|c: char| !c.is_whitespace()).unwrap_or(rest.len()))
+        } else if first.is_ascii_alphabetic() || first == '_' {
+            (TokenKind::Keyword, rest.find(|c: char| !(c.is_ascii_alphanumeric() || c == '_')).unwrap_or(rest.len()))
+        } else {
+            let len = PUNCTS.iter().find(|p| rest.starts_with(*p)).map_or(first.len_utf8(), |p| p.len());
+            (TokenKind::Punct, len)
+        };
+
+        tokens.push(Token {
+            kind,
+            span: Span { start: cursor, end: cursor + len },
+            text: rest[..len].to_string(),
+        });
+        cursor += len;
+    }
+
+    tokens
+}
+
+impl Cst {
+    // Mesma leitura do Tree::try_parse, então aceitam exatamente as mesmas entradas
+    pub fn parse(input: String) -> Result<Self, ParseError> {
+        let pair = Tree::parse_pair(&input)?;
+
+        let tree = Tree::from_pair(pair.clone());
+        let root = Node::from_pair(pair, &input);
+
+        Ok(Self { source: input, root, tree })
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    pub fn root(&self) -> &Node {
+        &self.root
+    }
+
+    // AST do mesmo texto
+    pub fn tree(&self) -> &Tree {
+        &self.tree
+    }
+
+    pub fn tokens(&self) -> Vec<&Token> {
+        self.root.tokens()
+    }
+
+    // Um nó por item de tree().sqls, na mesma ordem
+    pub fn statements(&self) -> Vec<&Node> {
+        self.root
+            .children
+            .iter()
+            .filter_map(|child| match child {
+                Element::Node(node) if is_statement(node.rule) => Some(node),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn edit<F: FnOnce(&mut Tree)>(&self, edit: F) -> String {
+        self.edit_with(&Formatter::default(), edit)
+    }
+
+    // Aplica a edição na AST e reescreve só os comandos que mudaram;
+    // o resto (inclusive espaços e comentários) sai byte a byte como no original
+    pub fn edit_with<F: FnOnce(&mut Tree)>(&self, formatter: &Formatter, edit: F) -> String {
+        let mut edited = self.tree.clone();
+        edit(&mut edited);
+
+        let old = &self.tree.sqls;
+        let new = &edited.sqls;
+        let segments = self.segments();
+
+        let mut out = match segments.first() {
+            Some(segment) => self.source[..segment.body.start].to_string(),
+            None => self.source.clone(),
+        };
+        let mut open = false; // último comando emitido ficou sem ";"
+        let (mut i, mut j) = (0, 0);
+
+        let mut matches = common(old, new);
+        matches.push((old.len(), new.len()));
+
+        for (next_i, next_j) in matches {
+            // Pares alterados no meio do caminho: troca só o corpo, mantém ";" e espaços
+            while i < next_i || j < next_j {
+                if i < next_i && j < next_j && is_comment(&old[i]) == is_comment(&new[j]) {
+                    let segment = &segments[i];
+                    out.push_str(&formatter.format(&new[j]));
+                    out.push_str(&self.source[segment.body.end..segment.tail.end]);
+                    open = segment.open;
+                    i += 1;
+                    j += 1;
+                } else if i < next_i {
+                    i += 1;
+                } else {
+                    insert(&mut out, &mut open, formatter, &new[j]);
+                    j += 1;
+                }
+            }
+
+            if let Some(segment) = segments.get(next_i) {
+                out.push_str(&self.source[segment.body.start..segment.tail.end]);
+                open = segment.open;
+            }
+            i = next_i + 1;
+            j = next_j + 1;
+        }
+
+        out
+    }
+
+    // Cada comando com o texto que o segue até o próximo: ";" e espaços
+    fn segments(&self) -> Vec<Segment> {
+        let statements = self.statements();
+
+        statements
+            .iter()
+            .enumerate()
+            .map(|(index, node)| {
+                let end = statements.get(index + 1).map_or(self.source.len(), |next| next.span.start);
+                let tail = Span { start: node.span.end, end };
+                let open = node.rule != Rule::comment && !self.source[tail.start..tail.end].contains(';');
+                Segment { body: node.span, tail, open }
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for Cst {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for token in self.tokens() {
+            f.write_str(&token.text)?;
+        }
+        Ok(())
+    }
+}
+
+struct Segment {
+    body: Span,
+    tail: Span,
+    open: bool,
+}
+
+fn is_statement(rule: Rule) -> bool {
+    matches!(rule, Rule::DDL | Rule::DML | Rule::DQL | Rule::TCL | Rule::DCL | Rule::explain | Rule::comment)
+}
+
+fn is_comment(sql: &Sql) -> bool {
+    matches!(sql, Sql::Comment(_))
+}
+
+// Comando novo em linha própria, sempre terminado
+fn insert(out: &mut String, open: &mut bool, formatter: &Formatter, sql: &Sql) {
+    if *open {
+        out.push(';');
+    }
+    if !out.is_empty() && !out.ends_with('\n') {
+        out.push('\n');
+    }
+
+    out.push_str(&formatter.format(sql));
+    if !is_comment(sql) {
+        out.push(';');
+    }
+    out.push('\n');
+    *open = false;
+}
+
+// Pares (antigo, novo) que não mudaram. Início e fim iguais casam direto;
+// a maior subsequência comum só roda no trecho alterado do meio
+fn common(old: &[Sql], new: &[Sql]) -> Vec<(usize, usize)> {
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let (old_middle, new_middle) = (&old[prefix..old.len() - suffix], &new[prefix..new.len() - suffix]);
+
+    let mut matches: Vec<_> = (0..prefix).map(|i| (i, i)).collect();
+    matches.extend(subsequence(old_middle, new_middle).into_iter().map(|(i, j)| (prefix + i, prefix + j)));
+    matches.extend((0..suffix).map(|k| (old.len() - suffix + k, new.len() - suffix + k)));
+    matches
+}
+
+fn subsequence(old: &[Sql], new: &[Sql]) -> Vec<(usize, usize)> {
+    let mut lengths = vec![vec![0; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i][j] = match old[i] == new[j] {
+                true => lengths[i + 1][j + 1] + 1,
+                false => lengths[i + 1][j].max(lengths[i][j + 1]),
+            };
+        }
+    }
+
+    let mut matches = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            matches.push((i, j));
+            i += 1;
+            j += 1;
+        } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sql::ast::dml::nutrients::Nutrients;
+    use crate::sql::ast::dql::sift::Sift;
+    use crate::sql::ast::dql::DQL;
+    use crate::sql::ast::sql::Sql;
+    use crate::sql::ast::tcl::TCL;
+    use crate::sql::parser::cst::{common, Cst, TokenKind};
+    use crate::sql::parser::parser::Rule;
+    use crate::sql::parser::tree::Tree;
+
+    const INPUT: &str = "\n  -- produtos\ncreate   TABLE Product (id int PRIMARY key,\n\ttitle Text);\n\
+        /* consulta */ Select id,title FROM Product where price>=10 ;\nBEGIN;\n";
+
+    #[test]
+    fn every_byte_is_kept() {
+        for input in [INPUT, "", "   ", "SELECT 1", "select\t1 ;", "SELECT a::int, 'x y' FROM t -- fim"] {
+            let cst = Cst::parse(input.to_string()).unwrap();
+            assert_eq!(cst.to_string(), input);
+            assert_eq!(cst.root().text(), input);
+        }
+    }
+
+    #[test]
+    fn tokens_cover_the_source_with_spans() {
+        let cst = Cst::parse(INPUT.to_string()).unwrap();
+
+        let mut cursor = 0;
+        for token in cst.tokens() {
+            assert_eq!(token.span.start, cursor);
+            assert_eq!(&INPUT[token.span.start..token.span.end], token.text);
+            cursor = token.span.end;
+        }
+        assert_eq!(cursor, INPUT.len());
+    }
+
+    #[test]
+    fn keywords_keep_their_spelling() {
+        let cst = Cst::parse("Select  a FROM t".to_string()).unwrap();
+        let tokens: Vec<_> = cst.tokens().into_iter().map(|t| (t.kind, t.text.as_str())).collect();
+
+        assert_eq!(tokens, vec![
+            (TokenKind::Keyword, "Select"),
+            (TokenKind::Whitespace, "  "),
+            (TokenKind::Ident, "a"),
+            (TokenKind::Whitespace, " "),
+            (TokenKind::Keyword, "FROM"),
+            (TokenKind::Whitespace, " "),
+            (TokenKind::Ident, "t"),
+        ]);
+    }
+
+    #[test]
+    fn words_are_classified_by_the_grammar() {
+        let cst = Cst::parse("EXPLAIN (FORMAT JSON, COSTS 0) SELECT CAST(a AS int) FROM t".to_string()).unwrap();
+        let words: Vec<_> = cst
+            .tokens()
+            .into_iter()
+            .filter(|t| !t.is_trivia() && t.kind != TokenKind::Punct)
+            .map(|t| (t.kind, t.text.as_str()))
+            .collect();
+
+        assert_eq!(words, vec![
+            (TokenKind::Keyword, "EXPLAIN"),
+            (TokenKind::Ident, "FORMAT"),
+            (TokenKind::Ident, "JSON"),
+            (TokenKind::Ident, "COSTS"),
+            (TokenKind::Literal, "0"),
+            (TokenKind::Keyword, "SELECT"),
+            (TokenKind::Keyword, "CAST"),
+            (TokenKind::Ident, "a"),
+            (TokenKind::Keyword, "AS"),
+            (TokenKind::Type, "int"),
+            (TokenKind::Keyword, "FROM"),
+            (TokenKind::Ident, "t"),
+        ]);
+    }
+
+    #[test]
+    fn rejects_what_tree_rejects() {
+        for input in [
+            "SELECT 1; garbage",
+            "SELECT a FROM t WHERE a == 1",
+            "CREATE TABLE t (price NUMERIC)",
+            "SELECT 99999999999999999999",
+        ] {
+            assert!(Cst::parse(input.to_string()).is_err(), "{}", input);
+            assert!(Tree::try_parse(input.to_string()).is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn long_files_only_diff_the_changed_middle() {
+        // Sem o corte de início e fim, a tabela 5001 x 5001 passaria de 200 MB
+        let old: Vec<_> = (0..5000).map(|n| Sql::TCL(TCL::Savepoint(format!("s{}", n)))).collect();
+        let mut new = old.clone();
+        new[2500] = Sql::TCL(TCL::Release("s2500".to_string()));
+        new.insert(4000, Sql::TCL(TCL::Commit { chain: None }));
+
+        let matches = common(&old, &new);
+        assert_eq!(matches.len(), 4999);
+        assert_eq!(matches[2500], (2501, 2501));
+        assert_eq!(matches[4998], (4999, 5000));
+
+        let input: String = (0..50).map(|n| format!("SELECT {};\n", n)).collect();
+        let cst = Cst::parse(input.clone()).unwrap();
+        let output = cst.edit(|tree| tree.sqls[25] = Sql::TCL(TCL::Savepoint("sp".to_string())));
+        assert_eq!(output, input.replace("SELECT 25;", "SAVEPOINT sp;"));
+    }
+
+    #[test]
+    fn trivia_and_statements() {
+        let cst = Cst::parse(INPUT.to_string()).unwrap();
+
+        let comments: Vec<_> = cst.tokens().into_iter().filter(|t| t.kind == TokenKind::Comment).map(|t| t.text.as_str()).collect();
+        assert_eq!(comments, vec!["-- produtos", "/* consulta */"]);
+        assert!(cst.tokens().iter().filter(|t| t.is_trivia()).all(|t| t.text.trim().is_empty() || t.kind == TokenKind::Comment));
+
+        let statements = cst.statements();
+        assert_eq!(statements.len(), cst.tree().sqls.len());
+        assert_eq!(statements[3].text(), "Select id,title FROM Product where price>=10");
+        assert_eq!(statements[3].find(Rule::comp_op)[0].text(), ">=");
+        assert_eq!(cst.tree(), &Tree::parse(INPUT.to_string()));
+    }
+
+    #[test]
+    fn untouched_edit_is_identity() {
+        let cst = Cst::parse(INPUT.to_string()).unwrap();
+        assert_eq!(cst.edit(|_| {}), INPUT);
+    }
+
+    #[test]
+    fn edit_rewrites_only_the_changed_statement() {
+        let cst = Cst::parse(INPUT.to_string()).unwrap();
+
+        let output = cst.edit(|tree| match &mut tree.sqls[3] {
+            Sql::DQL(DQL::Sample(sample)) => sample.gate = Some(Sift::Literal(Nutrients::Bool(true))),
+            other => panic!("Expected SELECT, got {:?}", other),
+        });
+
+        assert_eq!(
+            output,
+            "\n  -- produtos\ncreate   TABLE Product (id int PRIMARY key,\n\ttitle Text);\n\
+            /* consulta */ SELECT id, title FROM Product WHERE TRUE ;\nBEGIN;\n",
+        );
+    }
+
+    #[test]
+    fn edit_inserts_and_removes_statements() {
+        let cst = Cst::parse(INPUT.to_string()).unwrap();
+
+        let output = cst.edit(|tree| {
+            tree.sqls.remove(3);
+            tree.sqls.push(Sql::TCL(TCL::Commit { chain: None }));
+        });
+        assert_eq!(output, "\n  -- produtos\ncreate   TABLE Product (id int PRIMARY key,\n\ttitle Text);\n/* consulta */ BEGIN;\nCOMMIT;\n");
+
+        let expected = {
+            let mut tree = cst.tree().clone();
+            tree.sqls.remove(3);
+            tree.sqls.push(Sql::TCL(TCL::Commit { chain: None }));
+            tree
+        };
+        assert_eq!(Tree::parse(output), expected);
+    }
+
+    #[test]
+    fn appended_statement_closes_the_previous_one() {
+        let cst = Cst::parse("select 1".to_string()).unwrap();
+        let output = cst.edit(|tree| tree.sqls.push(Sql::TCL(TCL::Savepoint("sp".to_string()))));
+
+        assert_eq!(output, "select 1;\nSAVEPOINT sp;\n");
+    }
+}
@@ -0,0 +1,30 @@
+use pest::error::{Error, ErrorVariant, LineColLocation};
+use pest::iterators::Pair;
+use thiserror::Error;
+use crate::sql::parser::parser::Rule;
+
+// Entrada que a gramática não cobre por inteiro
+#[derive(Debug, Error)]
+#[error(transparent)]
+pub struct ParseError(Box<Error<Rule>>);
+
+impl ParseError {
+    pub(crate) fn out_of_range(number: Pair<Rule>) -> Self {
+        let message = format!("number out of range: {}", number.as_str());
+        ParseError::from(Error::new_from_span(ErrorVariant::CustomError { message }, number.as_span()))
+    }
+
+    // Linha e coluna (a partir de 1) onde a leitura parou
+    pub fn line_col(&self) -> (usize, usize) {
+        match self.0.line_col {
+            LineColLocation::Pos(line_col) => line_col,
+            LineColLocation::Span(start, _) => start,
+        }
+    }
+}
+
+impl From<Error<Rule>> for ParseError {
+    fn from(error: Error<Rule>) -> Self {
+        ParseError(Box::new(error))
+    }
+}
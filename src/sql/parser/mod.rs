@@ -1,2 +1,5 @@
+pub mod cst;
+pub mod error;
+#[allow(clippy::module_inception)]
 pub mod parser;
 pub mod tree;
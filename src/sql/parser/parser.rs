@@ -1,5 +1,40 @@
+use pest::iterators::Pair;
+use pest::Parser;
 use pest_derive::Parser;
 
 #[derive(Parser)]
 #[grammar = "sql/sql.pest"]
 pub struct SQLParser;
+
+impl SQLParser {
+    // Leitura de uma regra isolada para os from_input; como em Tree, nada pode sobrar da entrada
+    pub(crate) fn parse_whole(rule: Rule, input: &str) -> Pair<'_, Rule> {
+        let pair = SQLParser::parse(rule, input)
+            .expect("Failed to parse input")
+            .next()
+            .expect("No pair found");
+
+        // Um ";" final apenas encerra o comando
+        let rest = input[pair.as_span().end()..].trim_start();
+        let rest = rest.strip_prefix(';').unwrap_or(rest);
+        assert!(rest.trim().is_empty(), "Input not fully parsed, left: {:?}", rest);
+        pair
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sql::parser::parser::{Rule, SQLParser};
+
+    #[test]
+    fn whole_input_is_read() {
+        assert_eq!(SQLParser::parse_whole(Rule::sift, "a + b").as_str(), "a + b");
+        assert_eq!(SQLParser::parse_whole(Rule::sample, "SELECT 1;\n").as_str(), "SELECT 1");
+    }
+
+    #[test]
+    #[should_panic(expected = "Input not fully parsed")]
+    fn leftover_input_is_rejected() {
+        SQLParser::parse_whole(Rule::sift, "a + b c");
+    }
+}
@@ -1,6 +1,8 @@
+use pest::iterators::Pair;
 use pest::Parser;
 use serde::{Deserialize, Serialize};
 use crate::sql::ast::sql::Sql;
+use crate::sql::parser::error::ParseError;
 use crate::sql::parser::parser::{Rule, SQLParser};
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -10,13 +12,35 @@ pub struct Tree {
 
 impl Tree {
     pub fn parse(input: String) -> Self {
-        let parsed = SQLParser::parse(Rule::sql, &input).unwrap();
-        let pair = parsed.clone().next().unwrap();
+        Tree::try_parse(input).expect("Failed to parse input")
+    }
+
+    // Erro em vez de pânico; a entrada inteira precisa ser lida
+    pub fn try_parse(input: String) -> Result<Self, ParseError> {
+        Ok(Tree::from_pair(Tree::parse_pair(&input)?))
+    }
 
-        let sqls = Sql::from_pair(pair);
+    pub fn from_pair(pair: Pair<Rule>) -> Self {
+        assert_eq!(pair.as_rule(), Rule::sql);
 
         Self {
-            sqls,
+            sqls: Sql::from_pair(pair),
+        }
+    }
+
+    // Leitura de SOI a EOI, compartilhada com a Cst
+    pub(crate) fn parse_pair(input: &str) -> Result<Pair<'_, Rule>, ParseError> {
+        let pair = SQLParser::parse(Rule::sql, input)?.next().unwrap();
+
+        // A gramática aceita qualquer sequência de dígitos; o que não cabe em i64 vira erro aqui
+        let overflow = pair
+            .clone()
+            .into_inner()
+            .flatten()
+            .find(|p| p.as_rule() == Rule::number && p.as_str().parse::<i64>().is_err());
+        match overflow {
+            Some(number) => Err(ParseError::out_of_range(number)),
+            None => Ok(pair),
         }
     }
 }
@@ -0,0 +1,189 @@
+use crate::sql::ast::dcl::grant::{DropBehavior, Grant, GrantRole, Revoke, RevokeRole};
+use crate::sql::ast::dcl::privilege::{Privilege, PrivilegeKind, Privileges, Securable, SecurableKind};
+use crate::sql::ast::dcl::role::{Role, RoleKeyword, RoleOption};
+use crate::sql::ast::dcl::DCL;
+use crate::sql::printer::doc::{clause, concat, kw, list, parens, space, text, words, Doc};
+use crate::sql::printer::ToDoc;
+
+impl ToDoc for DCL {
+    fn to_doc(&self) -> Doc {
+        match self {
+            DCL::Grant(grant) => grant.to_doc(),
+            DCL::Revoke(revoke) => revoke.to_doc(),
+            DCL::GrantRole(grant) => grant.to_doc(),
+            DCL::RevokeRole(revoke) => revoke.to_doc(),
+            DCL::CreateRole(role) => role.to_doc(),
+            DCL::DropRole { keyword, if_exists, roles } => words(
+                [
+                    Some(kw("DROP")),
+                    Some(keyword.to_doc()),
+                    if_exists.then(|| kw("IF EXISTS")),
+                    Some(list(roles.iter().map(text))),
+                ]
+                .into_iter()
+                .flatten(),
+            ),
+        }
+    }
+}
+
+impl ToDoc for Grant {
+    fn to_doc(&self) -> Doc {
+        let mut docs = vec![
+            clause(kw("GRANT"), self.privileges.to_doc()),
+            Doc::Line,
+            clause(kw("ON"), self.on.to_doc()),
+            Doc::Line,
+            clause(kw("TO"), list(self.grantees.iter().map(text))),
+        ];
+        if self.grant_option {
+            docs.extend([Doc::Line, kw("WITH GRANT OPTION")]);
+        }
+        Doc::Group(Box::new(concat(docs)))
+    }
+}
+
+impl ToDoc for Revoke {
+    fn to_doc(&self) -> Doc {
+        let head = match self.grant_option {
+            true => kw("REVOKE GRANT OPTION FOR"),
+            false => kw("REVOKE"),
+        };
+
+        let mut docs = vec![
+            clause(head, self.privileges.to_doc()),
+            Doc::Line,
+            clause(kw("ON"), self.on.to_doc()),
+            Doc::Line,
+            clause(kw("FROM"), list(self.grantees.iter().map(text))),
+        ];
+        if let Some(behavior) = &self.behavior {
+            docs.extend([Doc::Line, behavior.to_doc()]);
+        }
+        Doc::Group(Box::new(concat(docs)))
+    }
+}
+
+impl ToDoc for GrantRole {
+    fn to_doc(&self) -> Doc {
+        Doc::Group(Box::new(concat(vec![
+            clause(kw("GRANT"), list(self.roles.iter().map(text))),
+            Doc::Line,
+            clause(kw("TO"), list(self.grantees.iter().map(text))),
+        ])))
+    }
+}
+
+impl ToDoc for RevokeRole {
+    fn to_doc(&self) -> Doc {
+        let mut docs = vec![
+            clause(kw("REVOKE"), list(self.roles.iter().map(text))),
+            Doc::Line,
+            clause(kw("FROM"), list(self.grantees.iter().map(text))),
+        ];
+        if let Some(behavior) = &self.behavior {
+            docs.extend([Doc::Line, behavior.to_doc()]);
+        }
+        Doc::Group(Box::new(concat(docs)))
+    }
+}
+
+impl ToDoc for DropBehavior {
+    fn to_doc(&self) -> Doc {
+        kw(match self {
+            DropBehavior::Cascade => "CASCADE",
+            DropBehavior::Restrict => "RESTRICT",
+        })
+    }
+}
+
+impl ToDoc for Privileges {
+    fn to_doc(&self) -> Doc {
+        match self {
+            Privileges::All => kw("ALL PRIVILEGES"),
+            Privileges::Listed(privileges) => list(privileges.iter().map(ToDoc::to_doc)),
+        }
+    }
+}
+
+impl ToDoc for Privilege {
+    fn to_doc(&self) -> Doc {
+        match self.piths.is_empty() {
+            true => self.kind.to_doc(),
+            false => words([self.kind.to_doc(), parens(list(self.piths.iter().map(text)))]),
+        }
+    }
+}
+
+impl ToDoc for PrivilegeKind {
+    fn to_doc(&self) -> Doc {
+        kw(match self {
+            PrivilegeKind::Select => "SELECT",
+            PrivilegeKind::Insert => "INSERT",
+            PrivilegeKind::Update => "UPDATE",
+            PrivilegeKind::Delete => "DELETE",
+            PrivilegeKind::Truncate => "TRUNCATE",
+            PrivilegeKind::References => "REFERENCES",
+            PrivilegeKind::Trigger => "TRIGGER",
+            PrivilegeKind::Usage => "USAGE",
+            PrivilegeKind::Create => "CREATE",
+            PrivilegeKind::Connect => "CONNECT",
+            PrivilegeKind::Temporary => "TEMPORARY",
+            PrivilegeKind::Execute => "EXECUTE",
+        })
+    }
+}
+
+impl ToDoc for Securable {
+    fn to_doc(&self) -> Doc {
+        words([self.kind.to_doc(), list(self.buds.iter().map(text))])
+    }
+}
+
+impl ToDoc for SecurableKind {
+    fn to_doc(&self) -> Doc {
+        kw(match self {
+            SecurableKind::Table => "TABLE",
+            SecurableKind::Schema => "SCHEMA",
+            SecurableKind::Database => "DATABASE",
+            SecurableKind::Sequence => "SEQUENCE",
+            SecurableKind::Function => "FUNCTION",
+        })
+    }
+}
+
+impl ToDoc for Role {
+    fn to_doc(&self) -> Doc {
+        let mut docs = vec![kw("CREATE"), space(), self.keyword.to_doc(), space(), text(&self.bud)];
+        if !self.options.is_empty() {
+            docs.extend([space(), kw("WITH"), space(), words(self.options.iter().map(ToDoc::to_doc))]);
+        }
+        concat(docs)
+    }
+}
+
+impl ToDoc for RoleKeyword {
+    fn to_doc(&self) -> Doc {
+        kw(match self {
+            RoleKeyword::Role => "ROLE",
+            RoleKeyword::User => "USER",
+        })
+    }
+}
+
+impl ToDoc for RoleOption {
+    fn to_doc(&self) -> Doc {
+        let flag = |enabled: &bool, on: &'static str, off: &'static str| kw(if *enabled { on } else { off });
+
+        match self {
+            RoleOption::Login(enabled) => flag(enabled, "LOGIN", "NOLOGIN"),
+            RoleOption::Superuser(enabled) => flag(enabled, "SUPERUSER", "NOSUPERUSER"),
+            RoleOption::CreateDb(enabled) => flag(enabled, "CREATEDB", "NOCREATEDB"),
+            RoleOption::CreateRole(enabled) => flag(enabled, "CREATEROLE", "NOCREATEROLE"),
+            RoleOption::Inherit(enabled) => flag(enabled, "INHERIT", "NOINHERIT"),
+            RoleOption::Password(Some(password)) => words([kw("PASSWORD"), text(format!("'{}'", password))]),
+            RoleOption::Password(None) => kw("PASSWORD NULL"),
+            RoleOption::ConnectionLimit(limit) => words([kw("CONNECTION LIMIT"), text(limit.to_string())]),
+        }
+    }
+}
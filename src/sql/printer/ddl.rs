@@ -0,0 +1,60 @@
+use crate::sql::ast::ddl::anchor::Anchor;
+use crate::sql::ast::ddl::fauna::Fauna;
+use crate::sql::ast::ddl::seed::Seed;
+use crate::sql::ast::ddl::stem::Stem;
+use crate::sql::ast::ddl::DDL;
+use crate::sql::printer::doc::{concat, items, kw, nest, space, text, words, Doc};
+use crate::sql::printer::ToDoc;
+
+impl ToDoc for DDL {
+    fn to_doc(&self) -> Doc {
+        match self {
+            DDL::Seed(seed) => seed.to_doc(),
+        }
+    }
+}
+
+impl ToDoc for Seed {
+    fn to_doc(&self) -> Doc {
+        let stems = items(self.stems.iter().map(ToDoc::to_doc));
+
+        // Colunas abrem uma por linha quando o Formatter pede
+        Doc::Columns(Box::new(concat(vec![
+            kw("CREATE TABLE"),
+            space(),
+            text(&self.bud),
+            text(" ("),
+            nest(concat(vec![Doc::SoftLine, stems])),
+            Doc::SoftLine,
+            text(")"),
+        ])))
+    }
+}
+
+impl ToDoc for Stem {
+    fn to_doc(&self) -> Doc {
+        let mut docs = vec![text(&self.vein), self.fauna.to_doc()];
+        docs.extend(self.anchors.iter().map(ToDoc::to_doc));
+        words(docs)
+    }
+}
+
+impl ToDoc for Fauna {
+    fn to_doc(&self) -> Doc {
+        kw(match self {
+            Fauna::Int => "INT",
+            Fauna::Text => "TEXT",
+            Fauna::Bool => "BOOLEAN",
+        })
+    }
+}
+
+impl ToDoc for Anchor {
+    fn to_doc(&self) -> Doc {
+        kw(match self {
+            Anchor::Essence => "NOT NULL",
+            Anchor::Nucleus => "PRIMARY KEY",
+            Anchor::Axis => "UNIQUE",
+        })
+    }
+}
@@ -0,0 +1,225 @@
+use crate::sql::ast::dml::conflict::{Conflict, ConflictAction, ConflictTarget};
+use crate::sql::ast::dml::graft::Graft;
+use crate::sql::ast::dml::nutrients::Nutrients;
+use crate::sql::ast::dml::prune::Prune;
+use crate::sql::ast::dml::scion::{Scion, ScionAction};
+use crate::sql::ast::dml::soil::Soil;
+use crate::sql::ast::dml::sow::Sow;
+use crate::sql::ast::dml::splice::Splice;
+use crate::sql::ast::dml::tend::Tend;
+use crate::sql::ast::dml::DML;
+use crate::sql::ast::dql::grove::Grove;
+use crate::sql::ast::dql::root::Root;
+use crate::sql::ast::dql::sift::Sift;
+use crate::sql::ast::dql::twigs::Twigs;
+use crate::sql::printer::doc::{clause, concat, group, kw, list, parens, space, text, words, Doc};
+use crate::sql::printer::ToDoc;
+
+impl ToDoc for DML {
+    fn to_doc(&self) -> Doc {
+        match self {
+            DML::Sow(sow) => sow.to_doc(),
+            DML::Tend(tend) => tend.to_doc(),
+            DML::Prune(prune) => prune.to_doc(),
+            DML::Graft(graft) => graft.to_doc(),
+        }
+    }
+}
+
+// Cláusulas de um comando, uma por linha quando o todo não cabe
+pub(crate) fn statement(clauses: Vec<Option<Doc>>) -> Doc {
+    let mut docs = Vec::new();
+    for clause in clauses.into_iter().flatten() {
+        if !docs.is_empty() {
+            docs.push(Doc::Line);
+        }
+        docs.push(clause);
+    }
+    group(concat(docs))
+}
+
+// nome [AS alias]
+pub(crate) fn aliased(bud: &str, alias: &Option<String>) -> Doc {
+    match alias {
+        Some(alias) => words([text(bud), kw("AS"), text(alias)]),
+        None => text(bud),
+    }
+}
+
+fn where_clause(gate: &Option<Sift>) -> Option<Doc> {
+    gate.as_ref().map(|sift| clause(kw("WHERE"), sift.to_doc()))
+}
+
+fn returning(harvest: &Option<Twigs>) -> Option<Doc> {
+    harvest.as_ref().map(|twigs| clause(kw("RETURNING"), twigs.to_doc()))
+}
+
+fn set_clause(splices: &[Splice]) -> Doc {
+    clause(kw("SET"), list(splices.iter().map(ToDoc::to_doc)))
+}
+
+fn with_clause(grove: &Option<Grove>) -> Option<Doc> {
+    grove.as_ref().map(ToDoc::to_doc)
+}
+
+impl ToDoc for Sow {
+    fn to_doc(&self) -> Doc {
+        let target = words([kw("INSERT INTO"), text(&self.bud), parens(list(self.piths.iter().map(text)))]);
+
+        statement(vec![
+            with_clause(&self.grove),
+            Some(target),
+            Some(self.soil.to_doc()),
+            self.conflict.as_ref().map(ToDoc::to_doc),
+            returning(&self.harvest),
+        ])
+    }
+}
+
+impl ToDoc for Soil {
+    fn to_doc(&self) -> Doc {
+        match self {
+            Soil::Furrows(furrows) => clause(kw("VALUES"), list(furrows.iter().map(|furrow| furrow_doc(furrow)))),
+            Soil::Sample(sample) => sample.to_doc(),
+        }
+    }
+}
+
+fn furrow_doc(furrow: &[Sift]) -> Doc {
+    parens(list(furrow.iter().map(ToDoc::to_doc)))
+}
+
+impl ToDoc for Conflict {
+    fn to_doc(&self) -> Doc {
+        match self {
+            Conflict::OnConflict { target, action } => words(
+                [Some(kw("ON CONFLICT")), target.as_ref().map(ToDoc::to_doc), Some(action.to_doc())]
+                    .into_iter()
+                    .flatten(),
+            ),
+            Conflict::OnDuplicateKey(splices) => {
+                clause(kw("ON DUPLICATE KEY UPDATE"), list(splices.iter().map(ToDoc::to_doc)))
+            }
+        }
+    }
+}
+
+impl ToDoc for ConflictTarget {
+    fn to_doc(&self) -> Doc {
+        match self {
+            ConflictTarget::Piths { piths, gate } => statement(vec![
+                Some(parens(list(piths.iter().map(text)))),
+                where_clause(gate),
+            ]),
+            ConflictTarget::Constraint(name) => words([kw("ON CONSTRAINT"), text(name)]),
+        }
+    }
+}
+
+impl ToDoc for ConflictAction {
+    fn to_doc(&self) -> Doc {
+        match self {
+            ConflictAction::Nothing => kw("DO NOTHING"),
+            ConflictAction::Update { splices, gate } => statement(vec![
+                Some(concat(vec![kw("DO UPDATE"), space(), set_clause(splices)])),
+                where_clause(gate),
+            ]),
+        }
+    }
+}
+
+impl ToDoc for Splice {
+    fn to_doc(&self) -> Doc {
+        words([self.vein.to_doc(), text("="), self.sift.to_doc()])
+    }
+}
+
+impl ToDoc for Tend {
+    fn to_doc(&self) -> Doc {
+        statement(vec![
+            with_clause(&self.grove),
+            Some(words([kw("UPDATE"), aliased(&self.bud, &self.alias)])),
+            Some(set_clause(&self.splices)),
+            (!self.roots.is_empty()).then(|| clause(kw("FROM"), list(self.roots.iter().map(ToDoc::to_doc)))),
+            where_clause(&self.gate),
+            returning(&self.harvest),
+        ])
+    }
+}
+
+impl ToDoc for Prune {
+    fn to_doc(&self) -> Doc {
+        statement(vec![
+            with_clause(&self.grove),
+            Some(words([kw("DELETE FROM"), aliased(&self.bud, &self.alias)])),
+            (!self.roots.is_empty()).then(|| clause(kw("USING"), list(self.roots.iter().map(ToDoc::to_doc)))),
+            where_clause(&self.gate),
+            returning(&self.harvest),
+        ])
+    }
+}
+
+impl ToDoc for Graft {
+    fn to_doc(&self) -> Doc {
+        // A origem é um rootlet: junções precisam de parênteses
+        let stock = match &self.stock {
+            Root::Join { .. } => parens(self.stock.to_doc()),
+            _ => self.stock.to_doc(),
+        };
+
+        let mut clauses = vec![
+            with_clause(&self.grove),
+            Some(words([kw("MERGE INTO"), aliased(&self.bud, &self.alias)])),
+            Some(clause(kw("USING"), stock)),
+            Some(clause(kw("ON"), self.on.to_doc())),
+        ];
+        clauses.extend(self.scions.iter().map(|scion| Some(scion.to_doc())));
+        statement(clauses)
+    }
+}
+
+impl ToDoc for Scion {
+    fn to_doc(&self) -> Doc {
+        let matched = match self.matched {
+            true => kw("WHEN MATCHED"),
+            false => kw("WHEN NOT MATCHED"),
+        };
+
+        let mut docs = vec![matched];
+        if let Some(gate) = &self.gate {
+            docs.extend([kw("AND"), gate.to_doc()]);
+        }
+        docs.extend([kw("THEN"), self.action.to_doc()]);
+        words(docs)
+    }
+}
+
+impl ToDoc for ScionAction {
+    fn to_doc(&self) -> Doc {
+        match self {
+            ScionAction::Update(splices) => concat(vec![kw("UPDATE"), space(), set_clause(splices)]),
+            ScionAction::Delete => kw("DELETE"),
+            ScionAction::Nothing => kw("DO NOTHING"),
+            ScionAction::Insert { piths, furrow } => {
+                let mut docs = vec![kw("INSERT")];
+                if !piths.is_empty() {
+                    docs.push(parens(list(piths.iter().map(text))));
+                }
+                docs.extend([kw("VALUES"), furrow_doc(furrow)]);
+                words(docs)
+            }
+        }
+    }
+}
+
+impl ToDoc for Nutrients {
+    fn to_doc(&self) -> Doc {
+        match self {
+            Nutrients::Int(value) => text(value.to_string()),
+            Nutrients::Str(value) => text(format!("'{}'", value)),
+            Nutrients::Bool(true) => kw("TRUE"),
+            Nutrients::Bool(false) => kw("FALSE"),
+            Nutrients::Null => kw("NULL"),
+        }
+    }
+}
@@ -0,0 +1,82 @@
+// Documento intermediário: o texto e os pontos onde a linha pode quebrar
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Doc {
+    Text(String),
+    Keyword(&'static str), // caixa decidida pelo Formatter
+    Line,                  // espaço, ou quebra quando o grupo não cabe
+    SoftLine,              // nada, ou quebra quando o grupo não cabe
+    Comma,                 // separador de lista; posição da vírgula decidida pelo Formatter
+    Newline,               // quebra sempre
+    Concat(Vec<Doc>),
+    Nest(Box<Doc>),
+    Group(Box<Doc>),
+    Columns(Box<Doc>), // grupo que o Formatter pode forçar a quebrar (colunas do Seed)
+}
+
+pub fn text(s: impl Into<String>) -> Doc {
+    Doc::Text(s.into())
+}
+
+pub fn kw(keyword: &'static str) -> Doc {
+    Doc::Keyword(keyword)
+}
+
+pub fn space() -> Doc {
+    Doc::Text(" ".to_string())
+}
+
+pub fn concat(docs: Vec<Doc>) -> Doc {
+    Doc::Concat(docs)
+}
+
+pub fn nest(doc: Doc) -> Doc {
+    Doc::Nest(Box::new(doc))
+}
+
+pub fn group(doc: Doc) -> Doc {
+    Doc::Group(Box::new(doc))
+}
+
+// Itens separados por vírgula, que quebram todos juntos quando não cabem
+pub fn list<I: IntoIterator<Item = Doc>>(items: I) -> Doc {
+    group(self::items(items))
+}
+
+// Como list, mas quebra junto com o grupo que o contém
+pub fn items<I: IntoIterator<Item = Doc>>(items: I) -> Doc {
+    let mut docs = Vec::new();
+    for (i, item) in items.into_iter().enumerate() {
+        if i > 0 {
+            docs.push(Doc::Comma);
+        }
+        docs.push(item);
+    }
+    concat(docs)
+}
+
+// Itens separados por espaço simples
+pub fn words<I: IntoIterator<Item = Doc>>(items: I) -> Doc {
+    let mut docs = Vec::new();
+    for (i, item) in items.into_iter().enumerate() {
+        if i > 0 {
+            docs.push(space());
+        }
+        docs.push(item);
+    }
+    concat(docs)
+}
+
+// (doc) sem quebras
+pub fn parens(doc: Doc) -> Doc {
+    concat(vec![text("("), doc, text(")")])
+}
+
+// (doc) que, se não couber, abre em linhas indentadas
+pub fn block(doc: Doc) -> Doc {
+    group(concat(vec![text("("), nest(concat(vec![Doc::SoftLine, doc])), Doc::SoftLine, text(")")]))
+}
+
+// Cláusula: palavra-chave seguida do corpo, que desce indentado quando não cabe
+pub fn clause(head: Doc, body: Doc) -> Doc {
+    group(concat(vec![head, nest(concat(vec![Doc::Line, body]))]))
+}
@@ -0,0 +1,590 @@
+use crate::sql::ast::dml::nutrients::Nutrients;
+use crate::sql::ast::dql::aggregate::{Aggregate, AggregateFn};
+use crate::sql::ast::dql::bouquet::{Blend, Bouquet, Cross};
+use crate::sql::ast::dql::call::Call;
+use crate::sql::ast::dql::case::{Case, CaseWhen};
+use crate::sql::ast::dql::cluster::Cluster;
+use crate::sql::ast::dql::crop::{Crop, Limit};
+use crate::sql::ast::dql::distinct::Distinct;
+use crate::sql::ast::dql::grove::{Grove, Sapling};
+use crate::sql::ast::dql::ident::Ident;
+use crate::sql::ast::dql::op::{BinaryOp, Op, Quantifier, UnaryOp};
+use crate::sql::ast::dql::rank::{Direction, Nulls, Rank};
+use crate::sql::ast::dql::root::{JoinConstraint, JoinKind, Root};
+use crate::sql::ast::dql::sample::Sample;
+use crate::sql::ast::dql::sift::Sift;
+use crate::sql::ast::dql::twigs::{Twig, Twigs};
+use crate::sql::ast::dql::window::{Frame, FrameBound, FrameExclusion, FrameUnit, NamedWindow, Over, Window, WindowCall, WindowFn};
+use crate::sql::ast::dql::DQL;
+use crate::sql::printer::dml::{aliased, statement};
+use crate::sql::printer::doc::{block, clause, concat, group, kw, list, nest, parens, space, text, words, Doc};
+use crate::sql::printer::ToDoc;
+
+fn docs<T: ToDoc>(nodes: &[T]) -> Doc {
+    list(nodes.iter().map(ToDoc::to_doc))
+}
+
+fn order_by(ranks: &[Rank]) -> Option<Doc> {
+    (!ranks.is_empty()).then(|| clause(kw("ORDER BY"), docs(ranks)))
+}
+
+fn negated(negated: bool, keyword: &'static str, negated_keyword: &'static str) -> Doc {
+    kw(if negated { negated_keyword } else { keyword })
+}
+
+// [NOT] predicado com ESCAPE opcional
+fn pattern(sift: &Sift, op: Doc, pattern: &Sift, escape: &Option<Box<Sift>>) -> Doc {
+    let mut docs = vec![sift.to_doc(), op, pattern.to_doc()];
+    if let Some(escape) = escape {
+        docs.extend([kw("ESCAPE"), escape.to_doc()]);
+    }
+    words(docs)
+}
+
+fn filter(filter: &Option<Box<Sift>>) -> Option<Doc> {
+    filter.as_ref().map(|sift| concat(vec![space(), kw("FILTER"), space(), parens(words([kw("WHERE"), sift.to_doc()]))]))
+}
+
+impl ToDoc for DQL {
+    fn to_doc(&self) -> Doc {
+        match self {
+            DQL::Sample(sample) => sample.to_doc(),
+            DQL::Bouquet(bouquet) => bouquet.to_doc(),
+        }
+    }
+}
+
+impl ToDoc for Sample {
+    fn to_doc(&self) -> Doc {
+        let mut head = vec![kw("SELECT")];
+        head.extend(self.distinct.as_ref().map(|d| concat(vec![space(), d.to_doc()])));
+
+        statement(vec![
+            self.grove.as_ref().map(ToDoc::to_doc),
+            Some(clause(concat(head), self.piths.to_doc())),
+            (!self.roots.is_empty()).then(|| clause(kw("FROM"), docs(&self.roots))),
+            self.gate.as_ref().map(|sift| clause(kw("WHERE"), sift.to_doc())),
+            (!self.clusters.is_empty()).then(|| clause(kw("GROUP BY"), docs(&self.clusters))),
+            self.having.as_ref().map(|sift| clause(kw("HAVING"), sift.to_doc())),
+            (!self.windows.is_empty()).then(|| clause(kw("WINDOW"), docs(&self.windows))),
+            order_by(&self.ranks),
+            self.crop.as_ref().map(ToDoc::to_doc),
+        ])
+    }
+}
+
+impl ToDoc for Bouquet {
+    fn to_doc(&self) -> Doc {
+        statement(vec![
+            self.grove.as_ref().map(ToDoc::to_doc),
+            // Sem os parênteses, o ORDER BY de fora voltaria como parte do SELECT
+            Some(match &self.blend {
+                Blend::Sample(sample) => block(sample.to_doc()),
+                blend => blend.to_doc(),
+            }),
+            order_by(&self.ranks),
+            self.crop.as_ref().map(ToDoc::to_doc),
+        ])
+    }
+}
+
+impl ToDoc for Blend {
+    fn to_doc(&self) -> Doc {
+        match self {
+            // Operando sem parênteses não pode ter WITH, ORDER BY ou paginação
+            Blend::Sample(sample) if sample.grove.is_some() || !sample.ranks.is_empty() || sample.crop.is_some() => {
+                block(sample.to_doc())
+            }
+            Blend::Sample(sample) => sample.to_doc(),
+            Blend::Bouquet(bouquet) => block(bouquet.to_doc()),
+            Blend::Cross { left, cross, all, right } => {
+                let mut op = vec![cross.to_doc()];
+                op.extend(all.then(|| kw("ALL")));
+                concat(vec![left.to_doc(), Doc::Line, words(op), Doc::Line, right.to_doc()])
+            }
+        }
+    }
+}
+
+impl ToDoc for Cross {
+    fn to_doc(&self) -> Doc {
+        kw(match self {
+            Cross::Union => "UNION",
+            Cross::Intersect => "INTERSECT",
+            Cross::Except => "EXCEPT",
+        })
+    }
+}
+
+impl ToDoc for Grove {
+    fn to_doc(&self) -> Doc {
+        let head = match self.recursive {
+            true => kw("WITH RECURSIVE"),
+            false => kw("WITH"),
+        };
+        clause(head, docs(&self.saplings))
+    }
+}
+
+impl ToDoc for Sapling {
+    fn to_doc(&self) -> Doc {
+        let mut docs = vec![text(&self.bud)];
+        if !self.piths.is_empty() {
+            docs.push(parens(list(self.piths.iter().map(text))));
+        }
+        docs.push(kw("AS"));
+        docs.extend(self.materialized.map(|m| kw(if m { "MATERIALIZED" } else { "NOT MATERIALIZED" })));
+        docs.push(block(self.dql.to_doc()));
+        words(docs)
+    }
+}
+
+impl ToDoc for Distinct {
+    fn to_doc(&self) -> Doc {
+        match self {
+            Distinct::All => kw("ALL"),
+            Distinct::Distinct => kw("DISTINCT"),
+            Distinct::On(sifts) => words([kw("DISTINCT ON"), parens(docs(sifts))]),
+        }
+    }
+}
+
+impl ToDoc for Twigs {
+    fn to_doc(&self) -> Doc {
+        match self {
+            Twigs::All => text("*"),
+            Twigs::Named(twigs) => docs(twigs),
+        }
+    }
+}
+
+impl ToDoc for Twig {
+    fn to_doc(&self) -> Doc {
+        match self {
+            Twig::Sift { sift, alias: Some(alias) } => words([sift.to_doc(), kw("AS"), text(alias)]),
+            Twig::Sift { sift, alias: None } => sift.to_doc(),
+            Twig::Wildcard(Some(bud)) => text(format!("{}.*", bud)),
+            Twig::Wildcard(None) => text("*"),
+        }
+    }
+}
+
+impl ToDoc for Root {
+    fn to_doc(&self) -> Doc {
+        match self {
+            Root::Bud { bud, alias } => aliased(bud, alias),
+            Root::Sample { sample, alias } => {
+                let mut docs = vec![block(sample.to_doc())];
+                if let Some(alias) = alias {
+                    docs.extend([kw("AS"), text(alias)]);
+                }
+                words(docs)
+            }
+            Root::Join { left, kind, natural, right, constraint } => {
+                let mut join = Vec::new();
+                join.extend(natural.then(|| kw("NATURAL")));
+                join.push(kind.to_doc());
+
+                // Junções encadeiam à esquerda; à direita precisam de parênteses
+                join.push(match **right {
+                    Root::Join { .. } => parens(right.to_doc()),
+                    _ => right.to_doc(),
+                });
+                join.extend(constraint.as_ref().map(ToDoc::to_doc));
+
+                concat(vec![left.to_doc(), Doc::Line, words(join)])
+            }
+        }
+    }
+}
+
+impl ToDoc for JoinKind {
+    fn to_doc(&self) -> Doc {
+        kw(match self {
+            JoinKind::Inner => "JOIN",
+            JoinKind::Left => "LEFT JOIN",
+            JoinKind::Right => "RIGHT JOIN",
+            JoinKind::Full => "FULL JOIN",
+            JoinKind::Cross => "CROSS JOIN",
+        })
+    }
+}
+
+impl ToDoc for JoinConstraint {
+    fn to_doc(&self) -> Doc {
+        match self {
+            JoinConstraint::On(sift) => words([kw("ON"), sift.to_doc()]),
+            JoinConstraint::Using(piths) => words([kw("USING"), parens(list(piths.iter().map(text)))]),
+        }
+    }
+}
+
+impl ToDoc for Cluster {
+    fn to_doc(&self) -> Doc {
+        let sets = |head: &'static str, sets: &[Vec<Sift>]| {
+            words([kw(head), parens(list(sets.iter().map(|set| parens(docs(set)))))])
+        };
+
+        match self {
+            Cluster::Sift(sift) => sift.to_doc(),
+            Cluster::Rollup(rollup) => sets("ROLLUP", rollup),
+            Cluster::Cube(cube) => sets("CUBE", cube),
+            Cluster::GroupingSets(grouping) => sets("GROUPING SETS", grouping),
+        }
+    }
+}
+
+impl ToDoc for Rank {
+    fn to_doc(&self) -> Doc {
+        let mut docs = vec![self.sift.to_doc()];
+        docs.extend(self.direction.as_ref().map(ToDoc::to_doc));
+        docs.extend(self.nulls.as_ref().map(ToDoc::to_doc));
+        words(docs)
+    }
+}
+
+impl ToDoc for Direction {
+    fn to_doc(&self) -> Doc {
+        kw(match self {
+            Direction::Asc => "ASC",
+            Direction::Desc => "DESC",
+        })
+    }
+}
+
+impl ToDoc for Nulls {
+    fn to_doc(&self) -> Doc {
+        kw(match self {
+            Nulls::First => "NULLS FIRST",
+            Nulls::Last => "NULLS LAST",
+        })
+    }
+}
+
+impl ToDoc for Crop {
+    fn to_doc(&self) -> Doc {
+        let number = |n: u64| text(n.to_string());
+        let mut docs = Vec::new();
+
+        // LIMIT n [OFFSET m] | [OFFSET m ROWS] FETCH FIRST n ROWS ONLY | OFFSET m
+        if let Some(limit) = &self.limit {
+            docs.extend([kw("LIMIT"), limit.to_doc()]);
+            if let Some(offset) = self.offset {
+                docs.extend([kw("OFFSET"), number(offset)]);
+            }
+        } else {
+            if let Some(offset) = self.offset {
+                docs.extend([kw("OFFSET"), number(offset), kw("ROWS")]);
+            }
+            if let Some(fetch) = self.fetch {
+                docs.extend([kw("FETCH FIRST"), number(fetch), kw("ROWS ONLY")]);
+            }
+        }
+
+        words(docs)
+    }
+}
+
+impl ToDoc for Limit {
+    fn to_doc(&self) -> Doc {
+        match self {
+            Limit::Count(count) => text(count.to_string()),
+            Limit::All => kw("ALL"),
+        }
+    }
+}
+
+impl ToDoc for Sift {
+    fn to_doc(&self) -> Doc {
+        match self {
+            Sift::Or(left, right) => concat(vec![left.to_doc(), Doc::Line, kw("OR"), space(), right.to_doc()]),
+            Sift::And(left, right) => concat(vec![left.to_doc(), Doc::Line, kw("AND"), space(), right.to_doc()]),
+            Sift::Comparison { left, op, right } => words([left.to_doc(), op.to_doc(), right.to_doc()]),
+            Sift::Binary { left, op, right } => words([left.to_doc(), op.to_doc(), right.to_doc()]),
+            Sift::Unary { op: UnaryOp::Not, sift } => words([kw("NOT"), sift.to_doc()]),
+            Sift::Unary { op, sift } => {
+                // "- -x" e não "--x", que seria comentário
+                let separator = match (op, &**sift) {
+                    (UnaryOp::Neg, Sift::Unary { op: UnaryOp::Neg, .. }) => space(),
+                    (UnaryOp::Neg, Sift::Literal(Nutrients::Int(n))) if *n < 0 => space(),
+                    _ => text(""),
+                };
+                concat(vec![op.to_doc(), separator, sift.to_doc()])
+            }
+            Sift::Quantified { left, op, quantifier, sample } => {
+                words([left.to_doc(), op.to_doc(), quantifier.to_doc(), block(sample.to_doc())])
+            }
+            Sift::InSample { sift, sample, negated: n } => {
+                words([sift.to_doc(), negated(*n, "IN", "NOT IN"), block(sample.to_doc())])
+            }
+            Sift::InList { sift, list: items, negated: n } => {
+                words([sift.to_doc(), negated(*n, "IN", "NOT IN"), parens(docs(items))])
+            }
+            Sift::Like { sift, pattern: p, escape, negated: n, case_insensitive } => {
+                let op = match case_insensitive {
+                    true => negated(*n, "ILIKE", "NOT ILIKE"),
+                    false => negated(*n, "LIKE", "NOT LIKE"),
+                };
+                pattern(sift, op, p, escape)
+            }
+            Sift::SimilarTo { sift, pattern: p, escape, negated: n } => {
+                pattern(sift, negated(*n, "SIMILAR TO", "NOT SIMILAR TO"), p, escape)
+            }
+            Sift::Between { sift, low, high, negated: n } => {
+                words([sift.to_doc(), negated(*n, "BETWEEN", "NOT BETWEEN"), low.to_doc(), kw("AND"), high.to_doc()])
+            }
+            Sift::IsNull { sift, negated: n } => words([sift.to_doc(), negated(*n, "IS NULL", "IS NOT NULL")]),
+            Sift::Exists(sample) => words([kw("EXISTS"), block(sample.to_doc())]),
+            Sift::Sample(sample) => block(sample.to_doc()),
+            Sift::Ident(ident) => ident.to_doc(),
+            Sift::Excluded(vein) => concat(vec![kw("EXCLUDED"), text("."), text(vein)]),
+            Sift::InsertValue(vein) => concat(vec![kw("VALUES"), parens(text(vein))]),
+            Sift::Default => kw("DEFAULT"),
+            Sift::Case(case) => case.to_doc(),
+            Sift::Coalesce(sifts) => concat(vec![kw("COALESCE"), parens(docs(sifts))]),
+            Sift::NullIf(left, right) => concat(vec![kw("NULLIF"), parens(list([left.to_doc(), right.to_doc()]))]),
+            Sift::Greatest(sifts) => concat(vec![kw("GREATEST"), parens(docs(sifts))]),
+            Sift::Least(sifts) => concat(vec![kw("LEAST"), parens(docs(sifts))]),
+            Sift::Aggregate(aggregate) => aggregate.to_doc(),
+            Sift::Call(call) => call.to_doc(),
+            Sift::Cast { sift, fauna } => {
+                concat(vec![kw("CAST"), parens(words([sift.to_doc(), kw("AS"), fauna.to_doc()]))])
+            }
+            Sift::WindowCall(call) => call.to_doc(),
+            Sift::Windowed { sift, over } => words([sift.to_doc(), over.to_doc()]),
+            Sift::Literal(nutrients) => nutrients.to_doc(),
+            Sift::Paren(sift) => parens(sift.to_doc()),
+        }
+    }
+}
+
+impl ToDoc for Ident {
+    fn to_doc(&self) -> Doc {
+        text(self.parts().join("."))
+    }
+}
+
+impl ToDoc for Op {
+    fn to_doc(&self) -> Doc {
+        text(match self {
+            Op::Eq => "=",
+            Op::Neq => "<>",
+            Op::Lt => "<",
+            Op::Lte => "<=",
+            Op::Gt => ">",
+            Op::Gte => ">=",
+        })
+    }
+}
+
+impl ToDoc for BinaryOp {
+    fn to_doc(&self) -> Doc {
+        text(match self {
+            BinaryOp::Add => "+",
+            BinaryOp::Sub => "-",
+            BinaryOp::Mul => "*",
+            BinaryOp::Div => "/",
+            BinaryOp::Mod => "%",
+            BinaryOp::Concat => "||",
+            BinaryOp::BitAnd => "&",
+            BinaryOp::BitOr => "|",
+            BinaryOp::BitXor => "#",
+            BinaryOp::ShiftLeft => "<<",
+            BinaryOp::ShiftRight => ">>",
+        })
+    }
+}
+
+impl ToDoc for UnaryOp {
+    fn to_doc(&self) -> Doc {
+        match self {
+            UnaryOp::Not => kw("NOT"),
+            UnaryOp::Neg => text("-"),
+            UnaryOp::Plus => text("+"),
+            UnaryOp::BitNot => text("~"),
+        }
+    }
+}
+
+impl ToDoc for Quantifier {
+    fn to_doc(&self) -> Doc {
+        kw(match self {
+            Quantifier::Any => "ANY",
+            Quantifier::All => "ALL",
+        })
+    }
+}
+
+impl ToDoc for Case {
+    fn to_doc(&self) -> Doc {
+        let mut head = vec![kw("CASE")];
+        head.extend(self.operand.as_ref().map(|operand| concat(vec![space(), operand.to_doc()])));
+
+        let mut branches = Vec::new();
+        for branch in &self.branches {
+            branches.extend([Doc::Line, branch.to_doc()]);
+        }
+        if let Some(otherwise) = &self.otherwise {
+            branches.extend([Doc::Line, words([kw("ELSE"), otherwise.to_doc()])]);
+        }
+
+        group(concat(vec![concat(head), nest(concat(branches)), Doc::Line, kw("END")]))
+    }
+}
+
+impl ToDoc for CaseWhen {
+    fn to_doc(&self) -> Doc {
+        words([kw("WHEN"), self.when.to_doc(), kw("THEN"), self.then.to_doc()])
+    }
+}
+
+impl ToDoc for Aggregate {
+    fn to_doc(&self) -> Doc {
+        let mut args = Vec::new();
+        args.extend(self.distinct.then(|| kw("DISTINCT")));
+        match self.args.is_empty() {
+            true => args.push(text("*")),
+            false => args.push(docs(&self.args)),
+        }
+        if !self.ranks.is_empty() {
+            args.extend([kw("ORDER BY"), docs(&self.ranks)]);
+        }
+
+        let mut docs = vec![self.func.to_doc(), parens(words(args))];
+        docs.extend(filter(&self.filter));
+        concat(docs)
+    }
+}
+
+impl ToDoc for AggregateFn {
+    fn to_doc(&self) -> Doc {
+        kw(match self {
+            AggregateFn::Count => "COUNT",
+            AggregateFn::Sum => "SUM",
+            AggregateFn::Avg => "AVG",
+            AggregateFn::Min => "MIN",
+            AggregateFn::Max => "MAX",
+        })
+    }
+}
+
+impl ToDoc for Call {
+    fn to_doc(&self) -> Doc {
+        let name = match &self.schema {
+            Some(schema) => format!("{}.{}", schema, self.name),
+            None => self.name.clone(),
+        };
+
+        let mut args = Vec::new();
+        args.extend(self.distinct.then(|| kw("DISTINCT")));
+        if !self.args.is_empty() {
+            args.push(docs(&self.args));
+        }
+        if !self.ranks.is_empty() {
+            args.extend([kw("ORDER BY"), docs(&self.ranks)]);
+        }
+
+        let mut docs = vec![text(name), parens(words(args))];
+        docs.extend(filter(&self.filter));
+        concat(docs)
+    }
+}
+
+impl ToDoc for WindowCall {
+    fn to_doc(&self) -> Doc {
+        concat(vec![self.func.to_doc(), parens(docs(&self.args))])
+    }
+}
+
+impl ToDoc for WindowFn {
+    fn to_doc(&self) -> Doc {
+        kw(match self {
+            WindowFn::RowNumber => "ROW_NUMBER",
+            WindowFn::Rank => "RANK",
+            WindowFn::DenseRank => "DENSE_RANK",
+            WindowFn::PercentRank => "PERCENT_RANK",
+            WindowFn::CumeDist => "CUME_DIST",
+            WindowFn::Ntile => "NTILE",
+            WindowFn::Lag => "LAG",
+            WindowFn::Lead => "LEAD",
+            WindowFn::FirstValue => "FIRST_VALUE",
+            WindowFn::LastValue => "LAST_VALUE",
+            WindowFn::NthValue => "NTH_VALUE",
+        })
+    }
+}
+
+impl ToDoc for Over {
+    fn to_doc(&self) -> Doc {
+        match self {
+            Over::Named(name) => words([kw("OVER"), text(name)]),
+            Over::Window(window) => words([kw("OVER"), parens(window.to_doc())]),
+        }
+    }
+}
+
+impl ToDoc for Window {
+    fn to_doc(&self) -> Doc {
+        let mut docs = Vec::new();
+        docs.extend(self.base.as_ref().map(text));
+        if !self.partition.is_empty() {
+            docs.extend([kw("PARTITION BY"), self::docs(&self.partition)]);
+        }
+        if !self.ranks.is_empty() {
+            docs.extend([kw("ORDER BY"), self::docs(&self.ranks)]);
+        }
+        docs.extend(self.frame.as_ref().map(ToDoc::to_doc));
+        words(docs)
+    }
+}
+
+impl ToDoc for NamedWindow {
+    fn to_doc(&self) -> Doc {
+        words([text(&self.bud), kw("AS"), parens(self.window.to_doc())])
+    }
+}
+
+impl ToDoc for Frame {
+    fn to_doc(&self) -> Doc {
+        let mut docs = vec![self.unit.to_doc()];
+        match &self.end {
+            Some(end) => docs.extend([kw("BETWEEN"), self.start.to_doc(), kw("AND"), end.to_doc()]),
+            None => docs.push(self.start.to_doc()),
+        }
+        docs.extend(self.exclusion.as_ref().map(ToDoc::to_doc));
+        words(docs)
+    }
+}
+
+impl ToDoc for FrameUnit {
+    fn to_doc(&self) -> Doc {
+        kw(match self {
+            FrameUnit::Rows => "ROWS",
+            FrameUnit::Range => "RANGE",
+            FrameUnit::Groups => "GROUPS",
+        })
+    }
+}
+
+impl ToDoc for FrameBound {
+    fn to_doc(&self) -> Doc {
+        match self {
+            FrameBound::UnboundedPreceding => kw("UNBOUNDED PRECEDING"),
+            FrameBound::Preceding(sift) => words([sift.to_doc(), kw("PRECEDING")]),
+            FrameBound::CurrentRow => kw("CURRENT ROW"),
+            FrameBound::Following(sift) => words([sift.to_doc(), kw("FOLLOWING")]),
+            FrameBound::UnboundedFollowing => kw("UNBOUNDED FOLLOWING"),
+        }
+    }
+}
+
+impl ToDoc for FrameExclusion {
+    fn to_doc(&self) -> Doc {
+        kw(match self {
+            FrameExclusion::CurrentRow => "EXCLUDE CURRENT ROW",
+            FrameExclusion::Group => "EXCLUDE GROUP",
+            FrameExclusion::Ties => "EXCLUDE TIES",
+            FrameExclusion::NoOthers => "EXCLUDE NO OTHERS",
+        })
+    }
+}
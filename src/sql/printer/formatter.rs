@@ -0,0 +1,332 @@
+use serde::{Deserialize, Serialize};
+use crate::sql::parser::error::ParseError;
+use crate::sql::parser::tree::Tree;
+use crate::sql::printer::doc::Doc;
+use crate::sql::printer::ToDoc;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum KeywordCase {
+    Upper,
+    Lower,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CommaStyle {
+    Trailing, // a,\n    b
+    Leading,  // a\n    , b
+}
+
+// Opções de formatação; Default é o estilo usado para normalizar arquivos
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Formatter {
+    pub keyword_case: KeywordCase,
+    pub indent: usize,
+    pub line_width: usize,
+    pub comma: CommaStyle,
+    pub stem_per_line: bool, // uma coluna por linha no CREATE TABLE
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Flat,
+    Break,
+}
+
+impl Default for Formatter {
+    fn default() -> Self {
+        Self {
+            keyword_case: KeywordCase::Upper,
+            indent: 4,
+            line_width: 80,
+            comma: CommaStyle::Trailing,
+            stem_per_line: true,
+        }
+    }
+}
+
+impl Formatter {
+    // Uma linha só, usado pelo Display
+    pub fn compact() -> Self {
+        Self {
+            line_width: usize::MAX,
+            stem_per_line: false,
+            ..Self::default()
+        }
+    }
+
+    pub fn format<T: ToDoc + ?Sized>(&self, node: &T) -> String {
+        self.render(&node.to_doc())
+    }
+
+    // Reescreve um arquivo SQL; entrada que não é lida por inteiro é recusada
+    pub fn format_source(&self, input: String) -> Result<String, ParseError> {
+        Ok(self.format(&Tree::try_parse(input)?))
+    }
+
+    pub fn render(&self, doc: &Doc) -> String {
+        let mut out = String::new();
+        let mut column = 0;
+        let mut stack = vec![(0, Mode::Break, doc)];
+
+        while let Some((indent, mode, doc)) = stack.pop() {
+            match doc {
+                Doc::Text(s) => {
+                    out.push_str(s);
+                    column += s.len();
+                }
+                Doc::Keyword(k) => {
+                    out.push_str(&self.keyword(k));
+                    column += k.len();
+                }
+                Doc::Line | Doc::SoftLine if mode == Mode::Flat => {
+                    if *doc == Doc::Line {
+                        out.push(' ');
+                        column += 1;
+                    }
+                }
+                Doc::Comma if mode == Mode::Flat => {
+                    out.push_str(", ");
+                    column += 2;
+                }
+                Doc::Comma => match self.comma {
+                    CommaStyle::Trailing => {
+                        out.push(',');
+                        column = Self::newline(&mut out, indent);
+                    }
+                    CommaStyle::Leading => {
+                        column = Self::newline(&mut out, indent);
+                        out.push_str(", ");
+                        column += 2;
+                    }
+                },
+                Doc::Line | Doc::SoftLine | Doc::Newline => column = Self::newline(&mut out, indent),
+                Doc::Concat(docs) => stack.extend(docs.iter().rev().map(|d| (indent, mode, d))),
+                Doc::Nest(inner) => stack.push((indent + self.indent, mode, inner)),
+                Doc::Group(inner) | Doc::Columns(inner) => {
+                    let forced = matches!(doc, Doc::Columns(_)) && self.stem_per_line;
+                    let flat = mode == Mode::Flat
+                        || (!forced && self.fits(self.line_width.saturating_sub(column), inner));
+                    stack.push((indent, if flat { Mode::Flat } else { Mode::Break }, inner));
+                }
+            }
+        }
+
+        out
+    }
+
+    // O documento cabe inteiro em uma linha com a largura restante?
+    fn fits(&self, mut remaining: usize, doc: &Doc) -> bool {
+        let mut stack = vec![doc];
+
+        while let Some(doc) = stack.pop() {
+            let width = match doc {
+                Doc::Text(s) => s.len(),
+                Doc::Keyword(k) => k.len(),
+                Doc::Line => 1,
+                Doc::SoftLine => 0,
+                Doc::Comma => 2,
+                Doc::Newline => return false,
+                Doc::Columns(_) if self.stem_per_line => return false,
+                Doc::Concat(docs) => {
+                    stack.extend(docs.iter().rev());
+                    0
+                }
+                Doc::Nest(inner) | Doc::Group(inner) | Doc::Columns(inner) => {
+                    stack.push(inner);
+                    0
+                }
+            };
+
+            if width > remaining {
+                return false;
+            }
+            remaining -= width;
+        }
+
+        true
+    }
+
+    fn keyword(&self, keyword: &str) -> String {
+        match self.keyword_case {
+            KeywordCase::Upper => keyword.to_string(),
+            KeywordCase::Lower => keyword.to_lowercase(),
+        }
+    }
+
+    fn newline(out: &mut String, indent: usize) -> usize {
+        out.push('\n');
+        out.push_str(&" ".repeat(indent));
+        indent
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use crate::sql::ast::dql::sift::Sift;
+    use crate::sql::ast::sql::Sql;
+    use crate::sql::parser::tree::Tree;
+    use crate::sql::printer::formatter::{CommaStyle, Formatter, KeywordCase};
+
+    const CORPUS: &str = "
+        -- esquema
+        CREATE TABLE Product (id INT PRIMARY KEY, title TEXT NOT NULL, code TEXT UNIQUE, available BOOLEAN);
+        /* carga */
+        INSERT INTO Product (id, title) VALUES (1, 'Pen'), (2, 'Ink') ON CONFLICT (id) WHERE id > 0 DO UPDATE SET title = EXCLUDED.title RETURNING *;
+        INSERT INTO Archive (id, title) SELECT id, title FROM Product WHERE available = FALSE;
+        INSERT INTO Archive (id) SELECT id FROM Old UNION SELECT id FROM Older;
+        INSERT INTO Product (id, title) VALUES (3, 'Cap') ON DUPLICATE KEY UPDATE title = VALUES(title);
+        INSERT INTO Event (id, at, note) VALUES (-1, now(), NULL), (DEFAULT, DEFAULT, 'x' || 'y');
+        UPDATE Product p SET p.price = s.cost FROM Supply s WHERE s.product_id = p.id RETURNING p.id;
+        DELETE FROM Product AS p USING Supply s WHERE p.id = s.product_id AND s.cost < 0;
+        MERGE INTO Stock s USING Delivery d ON s.item = d.item
+            WHEN MATCHED AND d.qty = 0 THEN DELETE
+            WHEN MATCHED THEN UPDATE SET qty = s.qty + d.qty
+            WHEN NOT MATCHED THEN INSERT (item, qty) VALUES (d.item, DEFAULT);
+        WITH RECURSIVE tree (id, parent) AS (SELECT id, parent FROM Node WHERE parent = 0 UNION ALL SELECT n.id, n.parent FROM Node n JOIN tree t ON n.parent = t.id)
+            SELECT DISTINCT ON (t.id) t.*, COUNT(*) AS total, count(DISTINCT t.id, t.parent), MAX(t.id ORDER BY t.parent) FROM tree t LEFT JOIN (Node a CROSS JOIN Node b) ON TRUE
+            WHERE t.id IN (1, 2) AND NOT t.parent BETWEEN -1 AND 10 OR t.id NOT IN (SELECT id FROM Hidden)
+            GROUP BY ROLLUP (t.id, (t.parent)), GROUPING SETS ((), (t.id)) HAVING SUM(t.id) FILTER (WHERE t.id > 0) >= ALL (SELECT 1)
+            ORDER BY 1 DESC NULLS LAST LIMIT 10 OFFSET 5;
+        SELECT CASE WHEN a LIKE 'x%' ESCAPE '!' THEN - -a ELSE ~b END, CASE a WHEN 1 THEN 'one' END,
+            name NOT ILIKE 'a%', code SIMILAR TO '[0-9]+', COALESCE(a, NULLIF(b, 0), GREATEST(1, 2), LEAST(3, 4)),
+            CAST(a AS TEXT), left(a, 3), b::INT, a || b, a | b & c # d << 2, (a + b) * c % 4, EXISTS (SELECT 1),
+            pg_catalog.lower(DISTINCT name ORDER BY name) FILTER (WHERE ok), now(),
+            ROW_NUMBER() OVER w, LAG(price, 1) OVER (PARTITION BY region ORDER BY day ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW EXCLUDE TIES),
+            SUM(price) OVER (w RANGE 3 PRECEDING)
+            FROM Sales WINDOW w AS (ORDER BY day) OFFSET 2 ROWS FETCH FIRST 3 ROWS ONLY;
+        SELECT a FROM x OFFSET 1 LIMIT ALL;
+        SELECT public.t.*, t.a FROM public.t JOIN shop.public.u ON t.a = u.a;
+        (SELECT a FROM x) ORDER BY a;
+        ((SELECT a FROM x) UNION (SELECT a FROM y LIMIT 1)) LIMIT 2;
+        DELETE FROM Session WHERE owner IS NULL AND note IS NOT NULL OR note = NULL;
+        SELECT * FROM (SELECT a FROM x UNION SELECT a FROM y) AS u WHERE a IN (SELECT a FROM x INTERSECT SELECT b FROM z);
+        (SELECT a FROM x ORDER BY a LIMIT 1) EXCEPT SELECT b FROM y INTERSECT SELECT c FROM z ORDER BY 1;
+        BEGIN ISOLATION LEVEL SERIALIZABLE, READ ONLY;
+        SAVEPOINT sp;
+        ROLLBACK TO SAVEPOINT sp;
+        RELEASE SAVEPOINT sp;
+        COMMIT AND NO CHAIN;
+        SET TRANSACTION READ WRITE, NOT DEFERRABLE;
+        CREATE ROLE app WITH LOGIN NOSUPERUSER PASSWORD 'secret' CONNECTION LIMIT 5;
+        GRANT SELECT (id, title), UPDATE ON TABLE Product, Stock TO app, report WITH GRANT OPTION;
+        REVOKE GRANT OPTION FOR ALL PRIVILEGES ON SCHEMA billing FROM app CASCADE;
+        GRANT admin, report TO app;
+        REVOKE admin FROM app RESTRICT;
+        DROP ROLE IF EXISTS app, report;
+        EXPLAIN (ANALYZE, FORMAT JSON) SELECT 1;
+        EXPLAIN ANALYZE VERBOSE DELETE FROM Product;
+    ";
+
+    fn styles() -> Vec<Formatter> {
+        vec![
+            Formatter::default(),
+            Formatter::compact(),
+            Formatter { keyword_case: KeywordCase::Lower, ..Formatter::default() },
+            Formatter { line_width: 20, indent: 2, ..Formatter::default() },
+            Formatter { line_width: 1, comma: CommaStyle::Leading, ..Formatter::default() },
+            Formatter { comma: CommaStyle::Leading, stem_per_line: false, keyword_case: KeywordCase::Lower, ..Formatter::default() },
+        ]
+    }
+
+    #[test]
+    fn round_trip_in_every_style() {
+        let tree = Tree::parse(CORPUS.to_string());
+        assert_eq!(tree.sqls.len(), 34);
+
+        for formatter in styles() {
+            let formatted = formatter.format(&tree);
+            assert_eq!(Tree::parse(formatted.clone()), tree, "{:?} produziu:\n{}", formatter, formatted);
+        }
+    }
+
+    #[test]
+    fn round_trip_example_files() {
+        for path in ["eg/ddl/create.sql", "eg/ddl/product.sql"] {
+            let tree = Tree::parse(fs::read_to_string(path).unwrap());
+
+            for formatter in styles() {
+                assert_eq!(Tree::parse(formatter.format(&tree)), tree, "{} com {:?}", path, formatter);
+            }
+        }
+    }
+
+    #[test]
+    fn unparsable_input_is_rejected() {
+        for input in [
+            "SELECT a FROM t WHERE a == 1; SELECT 2",
+            "SELECT 1; garbage; SELECT 2",
+            "CREATE TABLE t (id INT, price NUMERIC); SELECT 1",
+        ] {
+            assert!(Tree::try_parse(input.to_string()).is_err(), "{}", input);
+            assert!(Formatter::default().format_source(input.to_string()).is_err(), "{}", input);
+        }
+
+        let error = Tree::try_parse("SELECT 1;\nSELECT 2 FROM;".to_string()).unwrap_err();
+        assert_eq!(error.line_col().0, 2);
+    }
+
+    #[test]
+    fn out_of_range_numbers_are_rejected() {
+        for input in ["SELECT 99999999999999999999", "INSERT INTO t (a) VALUES (9223372036854775808)"] {
+            assert!(Tree::try_parse(input.to_string()).is_err(), "{}", input);
+            assert!(Formatter::default().format_source(input.to_string()).is_err(), "{}", input);
+        }
+
+        let error = Tree::try_parse("SELECT 1;\nSELECT 99999999999999999999;".to_string()).unwrap_err();
+        assert_eq!(error.line_col(), (2, 8));
+        assert!(Tree::try_parse("SELECT 9223372036854775807".to_string()).is_ok());
+    }
+
+    #[test]
+    fn leading_whitespace_is_kept_out_of_the_tree() {
+        let formatted = Formatter::default().format_source("\n\n  select 1;\n".to_string()).unwrap();
+        assert_eq!(formatted, "SELECT 1;\n");
+    }
+
+    #[test]
+    fn formatting_is_idempotent() {
+        let tree = Tree::parse(CORPUS.to_string());
+
+        for formatter in styles() {
+            let once = formatter.format(&tree);
+            assert_eq!(formatter.format(&Tree::parse(once.clone())), once);
+        }
+    }
+
+    #[test]
+    fn display_is_single_line() {
+        let tree = Tree::parse("select a,b from t where a=1 and b<>2 order by a desc limit 3".to_string());
+        assert_eq!(tree.sqls[0].to_string(), "SELECT a, b FROM t WHERE a = 1 AND b <> 2 ORDER BY a DESC LIMIT 3");
+
+        let sift = Sift::from_input("x::INT + -(y)".to_string());
+        assert_eq!(sift.to_string(), "CAST(x AS INT) + -(y)");
+    }
+
+    #[test]
+    fn one_stem_per_line() {
+        let tree = Tree::parse("create table Product (id int primary key, title text)".to_string());
+        let formatter = Formatter::default();
+
+        assert_eq!(formatter.format(&tree), "CREATE TABLE Product (\n    id INT PRIMARY KEY,\n    title TEXT\n);\n");
+
+        let formatter = Formatter { stem_per_line: false, keyword_case: KeywordCase::Lower, ..Formatter::default() };
+        assert_eq!(formatter.format(&tree), "create table Product (id int primary key, title text);\n");
+    }
+
+    #[test]
+    fn narrow_width_breaks_clauses() {
+        let tree = Tree::parse("SELECT id, title FROM Product WHERE price > 10".to_string());
+
+        let formatter = Formatter { line_width: 20, ..Formatter::default() };
+        assert_eq!(formatter.format(&tree), "SELECT id, title\nFROM Product\nWHERE price > 10;\n");
+
+        let formatter = Formatter { line_width: 10, comma: CommaStyle::Leading, ..Formatter::default() };
+        assert_eq!(formatter.format(&tree), "SELECT\n    id\n    , title\nFROM\n    Product\nWHERE\n    price > 10;\n");
+
+        match &tree.sqls[0] {
+            Sql::DQL(_) => {}
+            other => panic!("Expected DQL, got {:?}", other),
+        }
+    }
+}
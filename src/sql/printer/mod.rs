@@ -0,0 +1,93 @@
+pub mod doc;
+pub mod formatter;
+
+mod dcl;
+mod ddl;
+mod dml;
+mod dql;
+mod sql;
+mod tcl;
+
+use std::fmt;
+use crate::sql::printer::doc::Doc;
+use crate::sql::printer::formatter::Formatter;
+
+// Nó que sabe se escrever de volta como SQL
+pub trait ToDoc {
+    fn to_doc(&self) -> Doc;
+}
+
+impl<T: ToDoc + ?Sized> ToDoc for Box<T> {
+    fn to_doc(&self) -> Doc {
+        (**self).to_doc()
+    }
+}
+
+// Display em uma linha para cada nó da AST
+macro_rules! display {
+    ($($node:ty),* $(,)?) => {
+        $(
+            impl fmt::Display for $node {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.write_str(&Formatter::compact().format(self))
+                }
+            }
+        )*
+    };
+}
+
+use crate::sql::ast::comment::Comment;
+use crate::sql::ast::dcl::grant::{DropBehavior, Grant, GrantRole, Revoke, RevokeRole};
+use crate::sql::ast::dcl::privilege::{Privilege, PrivilegeKind, Privileges, Securable, SecurableKind};
+use crate::sql::ast::dcl::role::{Role, RoleKeyword, RoleOption};
+use crate::sql::ast::dcl::DCL;
+use crate::sql::ast::ddl::anchor::Anchor;
+use crate::sql::ast::ddl::fauna::Fauna;
+use crate::sql::ast::ddl::seed::Seed;
+use crate::sql::ast::ddl::stem::Stem;
+use crate::sql::ast::ddl::DDL;
+use crate::sql::ast::dml::conflict::{Conflict, ConflictAction, ConflictTarget};
+use crate::sql::ast::dml::graft::Graft;
+use crate::sql::ast::dml::nutrients::Nutrients;
+use crate::sql::ast::dml::prune::Prune;
+use crate::sql::ast::dml::scion::{Scion, ScionAction};
+use crate::sql::ast::dml::soil::Soil;
+use crate::sql::ast::dml::sow::Sow;
+use crate::sql::ast::dml::splice::Splice;
+use crate::sql::ast::dml::tend::Tend;
+use crate::sql::ast::dml::DML;
+use crate::sql::ast::dql::aggregate::{Aggregate, AggregateFn};
+use crate::sql::ast::dql::bouquet::{Blend, Bouquet, Cross};
+use crate::sql::ast::dql::call::Call;
+use crate::sql::ast::dql::case::{Case, CaseWhen};
+use crate::sql::ast::dql::cluster::Cluster;
+use crate::sql::ast::dql::crop::{Crop, Limit};
+use crate::sql::ast::dql::distinct::Distinct;
+use crate::sql::ast::dql::grove::{Grove, Sapling};
+use crate::sql::ast::dql::ident::Ident;
+use crate::sql::ast::dql::op::{BinaryOp, Op, Quantifier, UnaryOp};
+use crate::sql::ast::dql::rank::{Direction, Nulls, Rank};
+use crate::sql::ast::dql::root::{JoinConstraint, JoinKind, Root};
+use crate::sql::ast::dql::sample::Sample;
+use crate::sql::ast::dql::sift::Sift;
+use crate::sql::ast::dql::twigs::{Twig, Twigs};
+use crate::sql::ast::dql::window::{Frame, FrameBound, FrameExclusion, FrameUnit, NamedWindow, Over, Window, WindowCall, WindowFn};
+use crate::sql::ast::dql::DQL;
+use crate::sql::ast::explain::{Explain, ExplainOption};
+use crate::sql::ast::sql::Sql;
+use crate::sql::ast::tcl::mode::{IsolationLevel, TransactionMode};
+use crate::sql::ast::tcl::TCL;
+use crate::sql::parser::tree::Tree;
+
+display!(
+    Tree, Sql, Comment, Explain, ExplainOption,
+    DDL, Seed, Stem, Fauna, Anchor,
+    DML, Sow, Soil, Conflict, ConflictTarget, ConflictAction, Tend, Splice, Prune, Graft, Scion, ScionAction, Nutrients,
+    DQL, Sample, Bouquet, Blend, Cross, Grove, Sapling, Distinct, Twigs, Twig, Root, JoinKind, JoinConstraint,
+    Cluster, Rank, Direction, Nulls, Crop, Limit, Sift, Ident, Op, BinaryOp, UnaryOp, Quantifier,
+    Aggregate, AggregateFn, Call, Case, CaseWhen, WindowCall, WindowFn, Over, Window, NamedWindow,
+    Frame, FrameUnit, FrameBound, FrameExclusion,
+    TCL, TransactionMode, IsolationLevel,
+    DCL, Grant, Revoke, GrantRole, RevokeRole, DropBehavior, Privileges, Privilege, PrivilegeKind,
+    Securable, SecurableKind, Role, RoleKeyword, RoleOption,
+);
@@ -0,0 +1,66 @@
+use crate::sql::ast::comment::Comment;
+use crate::sql::ast::explain::{Explain, ExplainOption};
+use crate::sql::ast::sql::Sql;
+use crate::sql::parser::tree::Tree;
+use crate::sql::printer::doc::{concat, kw, list, parens, space, text, words, Doc};
+use crate::sql::printer::ToDoc;
+
+impl ToDoc for Tree {
+    fn to_doc(&self) -> Doc {
+        // Um comando por linha; comentários não levam ponto e vírgula
+        concat(
+            self.sqls
+                .iter()
+                .map(|sql| match sql {
+                    Sql::Comment(_) => concat(vec![sql.to_doc(), Doc::Newline]),
+                    _ => concat(vec![sql.to_doc(), text(";"), Doc::Newline]),
+                })
+                .collect(),
+        )
+    }
+}
+
+impl ToDoc for Sql {
+    fn to_doc(&self) -> Doc {
+        match self {
+            Sql::DDL(ddl) => ddl.to_doc(),
+            Sql::DML(dml) => dml.to_doc(),
+            Sql::DQL(dql) => dql.to_doc(),
+            Sql::TCL(tcl) => tcl.to_doc(),
+            Sql::DCL(dcl) => dcl.to_doc(),
+            Sql::Explain(explain) => explain.to_doc(),
+            Sql::Comment(comment) => comment.to_doc(),
+        }
+    }
+}
+
+impl ToDoc for Comment {
+    fn to_doc(&self) -> Doc {
+        match self {
+            Comment::Line(line) => text(format!("--{}", line)),
+            Comment::Block(block) => text(format!("/*{}*/", block)),
+        }
+    }
+}
+
+impl ToDoc for Explain {
+    fn to_doc(&self) -> Doc {
+        let mut docs = vec![kw("EXPLAIN")];
+
+        // A forma entre parênteses já carrega ANALYZE e VERBOSE nas opções
+        if !self.options.is_empty() {
+            docs.push(parens(list(self.options.iter().map(ToDoc::to_doc))));
+        } else {
+            docs.extend(self.analyze.then(|| kw("ANALYZE")));
+            docs.extend(self.verbose.then(|| kw("VERBOSE")));
+        }
+
+        concat(vec![words(docs), space(), self.sql.to_doc()])
+    }
+}
+
+impl ToDoc for ExplainOption {
+    fn to_doc(&self) -> Doc {
+        words([Some(text(&self.name)), self.value.as_ref().map(text)].into_iter().flatten())
+    }
+}
@@ -0,0 +1,63 @@
+use crate::sql::ast::tcl::mode::{IsolationLevel, TransactionMode};
+use crate::sql::ast::tcl::{BeginKeyword, TCL};
+use crate::sql::printer::doc::{kw, list, text, words, Doc};
+use crate::sql::printer::ToDoc;
+
+impl ToDoc for TCL {
+    fn to_doc(&self) -> Doc {
+        let chain = |chain: &Option<bool>| chain.map(|chain| match chain {
+            true => kw("AND CHAIN"),
+            false => kw("AND NO CHAIN"),
+        });
+
+        match self {
+            TCL::Begin { keyword, modes } => {
+                let keyword = match keyword {
+                    BeginKeyword::Begin => kw("BEGIN"),
+                    BeginKeyword::Start => kw("START TRANSACTION"),
+                };
+                words([Some(keyword), modes_doc(modes)].into_iter().flatten())
+            }
+            TCL::SetTransaction(modes) => words([Some(kw("SET TRANSACTION")), modes_doc(modes)].into_iter().flatten()),
+            TCL::Commit { chain: c } => words([Some(kw("COMMIT")), chain(c)].into_iter().flatten()),
+            TCL::Rollback { savepoint, chain: c } => words(
+                [
+                    Some(kw("ROLLBACK")),
+                    savepoint.as_ref().map(|s| words([kw("TO SAVEPOINT"), text(s)])),
+                    chain(c),
+                ]
+                .into_iter()
+                .flatten(),
+            ),
+            TCL::Savepoint(name) => words([kw("SAVEPOINT"), text(name)]),
+            TCL::Release(name) => words([kw("RELEASE SAVEPOINT"), text(name)]),
+        }
+    }
+}
+
+fn modes_doc(modes: &[TransactionMode]) -> Option<Doc> {
+    (!modes.is_empty()).then(|| list(modes.iter().map(ToDoc::to_doc)))
+}
+
+impl ToDoc for TransactionMode {
+    fn to_doc(&self) -> Doc {
+        match self {
+            TransactionMode::Isolation(level) => words([kw("ISOLATION LEVEL"), level.to_doc()]),
+            TransactionMode::ReadOnly => kw("READ ONLY"),
+            TransactionMode::ReadWrite => kw("READ WRITE"),
+            TransactionMode::Deferrable => kw("DEFERRABLE"),
+            TransactionMode::NotDeferrable => kw("NOT DEFERRABLE"),
+        }
+    }
+}
+
+impl ToDoc for IsolationLevel {
+    fn to_doc(&self) -> Doc {
+        kw(match self {
+            IsolationLevel::ReadUncommitted => "READ UNCOMMITTED",
+            IsolationLevel::ReadCommitted => "READ COMMITTED",
+            IsolationLevel::RepeatableRead => "REPEATABLE READ",
+            IsolationLevel::Serializable => "SERIALIZABLE",
+        })
+    }
+}